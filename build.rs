@@ -0,0 +1,90 @@
+/// Build script that generates the tests for example manifests in `data/examples/NN.json`.
+/// See `src/template/examples.rs` for the manifest format.
+use std::{collections::HashMap, env, fs, path::Path, str::FromStr};
+use tinyjson::JsonValue;
+
+const PARTS: [(&str, u8); 2] = [("part_one", 1), ("part_two", 2)];
+
+/// Derive a valid, unique test function name for an example.
+fn test_name(part: &str, name: Option<&str>, index: usize, seen: &mut Vec<String>) -> String {
+    let suffix = name.map_or_else(
+        || index.to_string(),
+        |name| {
+            name.chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect()
+        },
+    );
+
+    let mut test_name = format!("{part}_{suffix}");
+    if seen.contains(&test_name) {
+        test_name = format!("{test_name}_{index}");
+    }
+    seen.push(test_name.clone());
+    test_name
+}
+
+/// Generate the tests of a manifest. Errors are reported by the tests of that day only, so that other days still build.
+fn generate_tests(manifest: &str, day: u8) -> Result<String, String> {
+    let path = format!("data/examples/{day:02}.json");
+    let json =
+        JsonValue::from_str(manifest).map_err(|_| format!("{path} is not a valid JSON file."))?;
+    let json = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| format!("{path} must contain a JSON object."))?;
+
+    let mut seen = vec![];
+    let mut tests = vec![];
+
+    for (part, part_number) in PARTS {
+        let Some(examples) = json.get(part) else {
+            continue;
+        };
+        let examples = examples
+            .get::<Vec<JsonValue>>()
+            .ok_or_else(|| format!("`{part}` of {path} must be an array."))?;
+
+        for (index, example) in examples.iter().enumerate() {
+            let name = example
+                .get::<HashMap<String, JsonValue>>()
+                .and_then(|e| e.get("name"))
+                .and_then(|n| n.get::<String>());
+
+            let test_name = test_name(part, name.map(String::as_str), index, &mut seen);
+            tests.push(format!(
+                "#[test]\nfn {test_name}() {{\n    ::advent_of_code::template::examples::check(DAY, {part_number}, {index}, {part});\n}}\n"
+            ));
+        }
+    }
+
+    Ok(tests.join("\n"))
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=data/examples");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for day in 1..=25_u8 {
+        let manifest_path = format!("data/examples/{day:02}.json");
+        println!("cargo::rerun-if-changed={manifest_path}");
+
+        let tests = match fs::read_to_string(&manifest_path) {
+            Ok(manifest) => generate_tests(&manifest, day).unwrap_or_else(|e| {
+                println!("cargo::warning={e}");
+                format!("compile_error!({e:?});\n")
+            }),
+            Err(_) => String::new(),
+        };
+
+        fs::write(out_dir.join(format!("{day}.rs")), tests).unwrap();
+    }
+}
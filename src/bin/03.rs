@@ -69,7 +69,6 @@ impl FromStr for BatteryLine {
 
 impl BatteryLine {
     fn get_max_joltage(&self, n: usize) -> u64 {
-        //print!("{}: ", self);
         let mut val = 0;
         let mut i = 0;
         let end = self.batteries.len() - n;
@@ -79,7 +78,7 @@ impl BatteryLine {
             val += b.joltage;
             i = b.position + 1;
         }
-        //println!("{}", val);
+        advent_of_code::trace!("{self}: {val}");
         val
     }
 }
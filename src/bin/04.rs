@@ -1,3 +1,6 @@
+#[cfg(feature = "viz")]
+use advent_of_code::template::viz::{Grid, Shade};
+
 advent_of_code::solution!(4);
 
 #[derive(Clone)]
@@ -62,6 +65,21 @@ impl RollMap {
             .collect();
         l - self.rolls.len()
     }
+
+    /// The remaining rolls, highlighting those that are cleared next.
+    #[cfg(feature = "viz")]
+    fn frame(&self, max_neighbors: u8) -> Grid {
+        let mut grid = Grid::new(self.w, self.h);
+        for &(x, y) in &self.rolls {
+            let shade = if self.map[y][x] <= max_neighbors {
+                Shade::Highlight
+            } else {
+                Shade::Foreground
+            };
+            grid.set(x, y, shade);
+        }
+        grid
+    }
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -71,8 +89,9 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let mut roll_map = RollMap::new(input);
-    let mut nc = roll_map.clear_rolls(4);
+    let mut nc = 0;
     loop {
+        advent_of_code::viz!(roll_map.frame(4));
         let nc_new = roll_map.clear_rolls(4);
         if nc_new == 0 {
             break;
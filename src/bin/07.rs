@@ -4,6 +4,9 @@ use std::{
     vec,
 };
 
+#[cfg(feature = "viz")]
+use advent_of_code::template::viz::{Grid, Shade};
+
 advent_of_code::solution!(7);
 
 struct BeamMap {
@@ -41,9 +44,21 @@ impl FromStr for BeamMap {
 }
 
 impl BeamMap {
+    /// The splitters, before any beam passed them.
+    #[cfg(feature = "viz")]
+    fn grid(&self) -> Grid {
+        let mut grid = Grid::new(self.size.1, self.size.0);
+        for &(y, x) in &self.split_points {
+            grid.set(x, y, Shade::Foreground);
+        }
+        grid
+    }
+
     fn count_splits(&mut self) -> u64 {
         let mut splits = 0;
         let mut beams = self.beams.clone();
+        #[cfg(feature = "viz")]
+        let mut grid = self.grid();
         for y in 1..self.size.0 {
             beams = beams
                 .iter()
@@ -56,6 +71,13 @@ impl BeamMap {
                     }
                 })
                 .collect();
+            advent_of_code::viz!({
+                for &(y, x) in &beams {
+                    grid.set(x, y, Shade::Highlight);
+                    grid.set(x, y - 1, Shade::Line);
+                }
+                grid.clone()
+            });
         }
         splits
     }
@@ -1,6 +1,15 @@
 use std::str::FromStr;
 
-advent_of_code::solution!(8);
+use advent_of_code::template::{params::RawParams, rng::Rng};
+
+advent_of_code::solution!(8, generator = generate);
+
+advent_of_code::params! {
+    pub struct Params {
+        /// Number of closest junction box pairs to connect in part one.
+        pub connections: usize,
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct JunctionBox {
@@ -52,7 +61,7 @@ impl Connections {
         } else {
             connections.len()
         };
-        connections.sort_by(|a, b| a.1.cmp(&b.1));
+        connections.sort_by_key(|a| a.1);
         for ((a_, b_), _) in connections[0..n].iter() {
             let mut aa = graph
                 .extract_if(0..graph.len(), |hs| hs.contains(a_))
@@ -78,22 +87,24 @@ impl Connections {
         }
     }
 
-    fn get_num_circuits(&mut self) -> u64 {
+    fn get_num_circuits(&mut self) -> Option<u64> {
         self.graph.sort_by_key(|g| std::cmp::Reverse(g.len()));
-        self.graph[0].len() as u64 * self.graph[1].len() as u64 * self.graph[2].len() as u64
+        let [a, b, c, ..] = &self.graph[..] else {
+            return None;
+        };
+        Some(a.len() as u64 * b.len() as u64 * c.len() as u64)
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &str, params: &Params) -> Option<u64> {
     let junctions: Vec<JunctionBox> = input
         .trim()
         .lines()
         .map(|line| line.parse::<JunctionBox>().unwrap())
         .collect();
-    let n = if junctions.len() < 1000 { 10 } else { 1000 };
-    let mut connections = Connections::new(junctions, Some(n));
+    let mut connections = Connections::new(junctions, Some(params.connections));
 
-    Some(connections.get_num_circuits())
+    connections.get_num_circuits()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -103,18 +114,41 @@ pub fn part_two(input: &str) -> Option<u64> {
         .map(|line| line.parse::<JunctionBox>().unwrap())
         .collect();
     let connections = Connections::new(junctions, None);
-    let (j1, j2) = connections.last_connection.unwrap();
+    let (j1, j2) = connections.last_connection?;
 
     Some(connections.junctions[j1].x as u64 * connections.junctions[j2].x as u64)
 }
 
+/// `size` junction boxes at random positions, spread over the same space as the real input.
+/// Like the real input, about as many pairs as boxes are connected, leaving at least three circuits.
+pub fn generate(size: usize, seed: u64) -> (String, RawParams) {
+    let mut rng = Rng::new(seed);
+    let input = (0..size)
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|()| rng.range(0..100_000));
+            format!("{x},{y},{z}\n")
+        })
+        .collect();
+
+    let connections = size.saturating_sub(3);
+    (
+        input,
+        RawParams::from([("connections".into(), connections.to_string())]),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!(8);
+
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", DAY),
+            &Params { connections: 10 },
+        );
         assert_eq!(result, Some(40));
     }
 
@@ -123,4 +157,25 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(25272));
     }
+
+    #[test]
+    fn test_generate() {
+        let (input, params) = generate(100, 1);
+        assert_eq!(input.lines().count(), 100);
+        assert_eq!(input, generate(100, 1).0);
+        assert_eq!(params["connections"], "97");
+        assert!(part_one(&input, &Params { connections: 50 }).is_some());
+    }
+
+    #[test]
+    fn test_generate_small_sizes() {
+        use advent_of_code::template::params::PuzzleParams;
+
+        for size in [1, 2, 3, 10, 45] {
+            let (input, params) = generate(size, 1);
+            let params = Params::from_params(&params).unwrap();
+            assert_eq!(part_one(&input, &params).is_some(), size >= 3);
+            assert_eq!(part_two(&input).is_some(), size >= 2);
+        }
+    }
 }
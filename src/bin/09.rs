@@ -1,6 +1,10 @@
 use std::{collections::BTreeMap, str::FromStr};
 
-advent_of_code::solution!(9);
+use advent_of_code::template::rng::Rng;
+#[cfg(feature = "viz")]
+use advent_of_code::template::viz::{Plot, Shade};
+
+advent_of_code::solution!(9, generator = generate);
 
 #[derive(Clone)]
 struct Point {
@@ -160,6 +164,15 @@ impl FromStr for Map {
 }
 
 impl Map {
+    /// The polygon of green tiles through the red tiles.
+    #[cfg(feature = "viz")]
+    fn plot(&self) -> Plot {
+        let corners: Vec<(i64, i64)> = self.points.iter().map(|p| (p.x, p.y)).collect();
+        Plot::new()
+            .polygon(&corners, Shade::Line)
+            .points(corners, Shade::Foreground)
+    }
+
     fn find_largest_area(&self) -> u64 {
         let mut largest_area = 0;
         for (i, p1) in self.points.iter().enumerate() {
@@ -182,6 +195,11 @@ impl Map {
                 if area > largest_area && lines.iter().all(|line| self.num_intersections(line) == 0)
                 {
                     largest_area = area;
+                    advent_of_code::viz!(self.plot().rect(
+                        (p1.x, p1.y),
+                        (p2.x, p2.y),
+                        Shade::Highlight
+                    ));
                 }
             }
         }
@@ -199,6 +217,29 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(map.find_largest_valid_area())
 }
 
+/// A random, rectilinear polygon of about `size` red tiles: a skyline of columns with random widths and heights.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let columns = (size.max(4) - 2) / 2;
+
+    let mut points = vec![(0, 0)];
+    let (mut x, mut height) = (0, 0);
+    for _ in 0..columns {
+        // consecutive columns differ in height, so that every point is a corner.
+        let mut next = rng.range(1..100_000);
+        while next == height {
+            next = rng.range(1..100_000);
+        }
+        points.push((x, next));
+        x += rng.range(2..200);
+        points.push((x, next));
+        height = next;
+    }
+    points.push((x, 0));
+
+    points.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +255,12 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(24));
     }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(100, 1);
+        assert_eq!(input.lines().count(), 100);
+        assert_eq!(input, generate(100, 1));
+        assert!(part_two(&input) <= part_one(&input));
+    }
 }
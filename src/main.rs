@@ -1,4 +1,7 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, auth, determinism, download, examples, leaderboard, minimize, read, report, scaffold,
+    scale, search, solve, time, tui,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
@@ -7,7 +10,14 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{
+        Day,
+        commands::{
+            auth::AuthAction,
+            time::{MergePolicy, VariantDisplay},
+        },
+        viz::VizOutput,
+    };
     use std::process;
 
     pub enum AppArguments {
@@ -16,17 +26,32 @@ mod args {
         },
         Read {
             day: Day,
+            part: Option<u8>,
+        },
+        Examples {
+            day: Day,
+            overwrite: bool,
         },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            dry_run: bool,
+        },
+        Report {
+            output: String,
+        },
+        Search {
+            query: String,
+            limit: usize,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            viz: Option<VizOutput>,
+            verbosity: u8,
         },
         All {
             release: bool,
@@ -35,6 +60,31 @@ mod args {
             all: bool,
             day: Option<Day>,
             store: bool,
+            merge: MergePolicy,
+            baseline: Option<String>,
+            profiles: bool,
+            variants: VariantDisplay,
+        },
+        Minimize {
+            day: Day,
+            part: u8,
+        },
+        CheckDeterminism {
+            day: Option<Day>,
+            runs: usize,
+        },
+        Scale {
+            day: Day,
+            sizes: Option<Vec<usize>>,
+            seed: u64,
+        },
+        Tui,
+        Auth {
+            action: AuthAction,
+        },
+        Leaderboard {
+            id: u64,
+            day: Option<Day>,
         },
         #[cfg(feature = "today")]
         Today,
@@ -50,29 +100,105 @@ mod args {
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let merge = args.opt_value_from_str("--merge")?.unwrap_or_default();
+                let baseline = args.opt_value_from_str("--baseline")?;
+                let profiles = args.contains("--profiles");
+                let variants = args.opt_value_from_str("--variants")?.unwrap_or_default();
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    merge,
+                    baseline,
+                    profiles,
+                    variants,
                 }
             }
+            Some("minimize") => AppArguments::Minimize {
+                part: args.value_from_str("--part")?,
+                day: args.free_from_str()?,
+            },
+            Some("check-determinism") => AppArguments::CheckDeterminism {
+                runs: args.opt_value_from_str("--runs")?.unwrap_or(5),
+                day: args.opt_free_from_str()?,
+            },
+            Some("scale") => AppArguments::Scale {
+                sizes: args.opt_value_from_fn("--sizes", parse_sizes)?,
+                seed: args.opt_value_from_str("--seed")?.unwrap_or(1),
+                day: args.free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
+                part: args.opt_value_from_str("--part")?,
+                day: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
                 day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                dry_run: args.contains("--dry-run"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("report") => AppArguments::Report {
+                output: args
+                    .opt_value_from_str("--output")?
+                    .unwrap_or_else(|| "data/report.html".into()),
+            },
+            Some("search") => {
+                let limit = args.opt_value_from_str("--limit")?.unwrap_or(10);
+                let mut terms: Vec<String> = vec![];
+                while let Some(term) = args.opt_free_from_str()? {
+                    terms.push(term);
+                }
+                AppArguments::Search {
+                    query: terms.join(" "),
+                    limit,
+                }
+            }
+            Some("solve") => {
+                // `--export png|gif` implies `--viz`, which plays the frames in the terminal.
+                let viz = args.contains("--viz").then_some(VizOutput::Play);
+                let export = args.opt_value_from_str("--export")?;
+                let mut verbosity = 0;
+                for (flag, level) in [("-vvv", 3), ("-vv", 2), ("-v", 1)] {
+                    while args.contains(flag) {
+                        verbosity += level;
+                    }
+                }
+
+                AppArguments::Solve {
+                    day: args.free_from_str()?,
+                    release: args.contains("--release"),
+                    submit: args.opt_value_from_str("--submit")?,
+                    dhat: args.contains("--dhat"),
+                    viz: export.or(viz),
+                    verbosity,
+                }
+            }
+            Some("tui") => AppArguments::Tui,
+            Some("auth") => {
+                let action = match args.opt_free_from_str::<String>()?.as_deref() {
+                    Some("login") => AuthAction::Login(args.opt_free_from_str()?),
+                    Some("status") => AuthAction::Status,
+                    Some("logout") => AuthAction::Logout,
+                    _ => {
+                        eprintln!(
+                            "Expected one of: auth login [SESSION], auth status, auth logout."
+                        );
+                        process::exit(1);
+                    }
+                };
+                AppArguments::Auth { action }
+            }
+            Some("leaderboard") => AppArguments::Leaderboard {
+                day: args.opt_value_from_str("--day")?,
+                id: args.free_from_str()?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -93,6 +219,11 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Parse a comma-separated list of input sizes, e.g. `250,500,1000`.
+    fn parse_sizes(s: &str) -> Result<Vec<usize>, std::num::ParseIntError> {
+        s.split(',').map(str::parse).collect()
+    }
 }
 
 fn main() {
@@ -103,32 +234,60 @@ fn main() {
         }
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                merge,
+                baseline,
+                profiles,
+                variants,
+            } => time::handle(
+                day,
+                all,
+                store,
+                merge,
+                baseline.as_deref(),
+                profiles,
+                variants,
+            ),
+            AppArguments::Minimize { day, part } => minimize::handle(day, part),
+            AppArguments::CheckDeterminism { day, runs } => determinism::handle(day, runs),
+            AppArguments::Scale { day, sizes, seed } => scale::handle(day, sizes, seed),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read { day, part } => read::handle(day, part),
+            AppArguments::Examples { day, overwrite } => examples::handle(day, overwrite),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                dry_run,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
+                scaffold::handle(day, overwrite, dry_run);
+                if download && !dry_run {
                     download::handle(day);
                 }
             }
+            AppArguments::Report { output } => report::handle(&output),
+            AppArguments::Search { query, limit } => search::handle(&query, limit),
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                viz,
+                verbosity,
+            } => solve::handle(day, release, dhat, submit, viz, verbosity),
+            AppArguments::Tui => tui::handle(),
+            AppArguments::Auth { action } => auth::handle(action),
+            AppArguments::Leaderboard { id, day } => leaderboard::handle(id, day),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
+                        scaffold::handle(day, false, false);
                         download::handle(day);
-                        read::handle(day)
+                        read::handle(day, None)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,191 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static ALLOCATIONS_FILE_PATH: &str = "./data/allocations.json";
+
+/// Represents the heap usage of a single part, as measured by `cargo solve --dhat`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    pub day: Day,
+    pub part: u8,
+    pub total_bytes: u64,
+    pub total_blocks: u64,
+    pub max_bytes: u64,
+}
+
+/// Represents the heap usage of all measured parts.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Allocations {
+    pub data: Vec<Allocation>,
+}
+
+impl Allocations {
+    /// Dehydrate allocations to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ALLOCATIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate allocations from a JSON file. If not present, returns empty allocations.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ALLOCATIONS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Allocations::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Insert the allocation of a part, replacing an earlier measurement.
+    pub fn insert(&mut self, allocation: Allocation) {
+        self.data
+            .retain(|a| a.day != allocation.day || a.part != allocation.part);
+        self.data.push(allocation);
+        self.data.sort_unstable_by_key(|a| (a.day, a.part));
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&Allocation> {
+        self.data.iter().find(|a| a.day == day && a.part == part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Allocations> for JsonValue {
+    fn from(value: Allocations) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Allocations {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Allocations {
+            data: json_data
+                .iter()
+                .map(Allocation::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Allocation> for JsonValue {
+    fn from(value: &Allocation) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let number = |n: u64| JsonValue::Number(n as f64);
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("total_bytes".into(), number(value.total_bytes));
+        map.insert("total_blocks".into(), number(value.total_blocks));
+        map.insert("max_bytes".into(), number(value.max_bytes));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Allocation {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected allocation to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected allocation.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .filter(|n| **n >= 0.0)
+                .map(|n| *n as u64)
+                .ok_or(format!(
+                    "Expected allocation.{key} to be a positive number."
+                ))
+        };
+
+        let part = number("part")?;
+        if part != 1 && part != 2 {
+            return Err("Expected allocation.part to be 1 or 2.".into());
+        }
+
+        Ok(Allocation {
+            day,
+            #[allow(clippy::cast_possible_truncation)]
+            part: part as u8,
+            total_bytes: number("total_bytes")?,
+            total_blocks: number("total_blocks")?,
+            max_bytes: number("max_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Allocation, Allocations};
+    use crate::day;
+
+    fn allocation(part: u8, total_bytes: u64) -> Allocation {
+        Allocation {
+            day: day!(3),
+            part,
+            total_bytes,
+            total_blocks: 2,
+            max_bytes: 10,
+        }
+    }
+
+    #[test]
+    fn replaces_measurements() {
+        let mut allocations = Allocations::default();
+        allocations.insert(allocation(2, 100));
+        allocations.insert(allocation(1, 100));
+        allocations.insert(allocation(2, 50));
+        assert_eq!(allocations.data.len(), 2);
+        assert_eq!(allocations.data[0].part, 1);
+        assert_eq!(allocations.get(day!(3), 2).unwrap().total_bytes, 50);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let allocations = Allocations {
+            data: vec![allocation(1, 1234)],
+        };
+        let json = JsonValue::from(allocations.clone()).stringify().unwrap();
+        let parsed = Allocations::try_from(json).unwrap();
+        assert_eq!(parsed.data, allocations.data);
+    }
+}
@@ -2,15 +2,18 @@
 use std::{
     fmt::Display,
     process::{Command, Output, Stdio},
+    time::Duration,
 };
 
-use crate::template::Day;
+use crate::template::{Day, auth, http};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    UnknownResponse,
+    ExpiredSession,
 }
 
 impl Display for AocCommandError {
@@ -21,6 +24,16 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::UnknownResponse => {
+                write!(
+                    f,
+                    "aoc-cli returned a response that could not be understood."
+                )
+            }
+            AocCommandError::ExpiredSession => write!(
+                f,
+                "expired session. Log in again with `cargo auth login` and a new session cookie."
+            ),
         }
     }
 }
@@ -72,15 +85,146 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Download the puzzle description only, e.g. to reveal part two once part one is solved.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(day);
+
+    let args = build_args(
+        "download",
+        &[
+            "--overwrite".into(),
+            "--puzzle-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path.to_string(),
+        ],
+        day,
+    );
+
+    let output = call_aoc_cli(&args)?;
+    println!("---");
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(output)
+}
+
+pub fn submit(day: Day, part: u8, result: &str) -> Result<Verdict, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // capture stdout so that the response can be parsed.
+    let output = Command::new("aoc")
+        .args(&args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    let response = String::from_utf8_lossy(&output.stdout);
+    print!("{response}");
+
+    if !output.status.success() {
+        return Err(check_session(AocCommandError::BadExitStatus(output)));
+    }
+
+    Verdict::parse(&response).ok_or_else(|| check_session(AocCommandError::UnknownResponse))
+}
+
+/// Report a failure as an expired session, if the server no longer accepts the session.
+fn check_session(error: AocCommandError) -> AocCommandError {
+    let Some((session, _)) = auth::current_session() else {
+        return error;
+    };
+
+    match auth::validate(&http::base_url(), &session) {
+        Ok(false) => AocCommandError::ExpiredSession,
+        _ => error,
+    }
 }
 
-fn get_input_path(day: Day) -> String {
+/// The verdict on a submitted answer, parsed from the response of the server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    /// An answer was submitted too recently. Holds how long to wait before submitting again.
+    RateLimited(Duration),
+}
+
+impl Verdict {
+    pub fn parse(response: &str) -> Option<Self> {
+        // aoc-cli wraps the response to the width of the terminal.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Some(Verdict::TooHigh)
+            } else if response.contains("too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if response.contains("You gave an answer too recently") {
+            let wait = parse_wait(&response).unwrap_or(Duration::from_secs(60));
+            Some(Verdict::RateLimited(wait))
+        } else if response.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_correct(self) -> bool {
+        self == Verdict::Correct
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited for {}", format_wait(*wait)),
+        }
+    }
+}
+
+/// Parse the wait of a rate limit, e.g. "You have 4m 29s left to wait".
+fn parse_wait(response: &str) -> Option<Duration> {
+    let (_, wait) = response.split_once("You have ")?;
+    let (wait, _) = wait.split_once(" left to wait")?;
+
+    wait.split(' ')
+        .map(|amount| {
+            let (unit, seconds) = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find(|(unit, _)| amount.ends_with(unit))?;
+            let value: u64 = amount.strip_suffix(unit)?.parse().ok()?;
+            Some(value * seconds)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Format a wait like the server does, e.g. "4m 29s".
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 3600, seconds / 60 % 60, seconds % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m {s}s"),
+        (h, m, s) => format!("{h}h {m}m {s}s"),
+    }
+}
+
+pub fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
@@ -88,7 +232,7 @@ fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -103,6 +247,11 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
         cmd_args.push(year.to_string());
     }
 
+    if let Some(path) = auth::get_path_for_session().filter(|path| path.exists()) {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(path.display().to_string());
+    }
+
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
     cmd_args
@@ -120,6 +269,56 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
     if output.status.success() {
         Ok(output)
     } else {
-        Err(AocCommandError::BadExitStatus(output))
+        Err(check_session(AocCommandError::BadExitStatus(output)))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Verdict, format_wait};
+
+    #[test]
+    fn parses_verdicts() {
+        let verdict = |response: &str| Verdict::parse(response);
+
+        assert_eq!(
+            verdict("That's the right answer!  You are one gold star closer to decorating."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high.  If you're stuck, ..."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too\nlow."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  If you're stuck, make sure ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(verdict("<!DOCTYPE html>"), None);
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        let response = "You gave an answer too recently; you have to wait after submitting an answer \
+            before trying again.  You have 4m\n29s left to wait.";
+        let verdict = Verdict::parse(response);
+
+        assert_eq!(
+            verdict,
+            Some(Verdict::RateLimited(Duration::from_secs(269)))
+        );
+        assert_eq!(verdict.unwrap().to_string(), "rate limited for 4m 29s");
+        assert_eq!(format_wait(Duration::from_secs(37)), "37s");
     }
 }
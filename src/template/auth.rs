@@ -0,0 +1,143 @@
+/// Management of the session cookie that authenticates requests to the puzzle server.
+/// `cargo auth login` stores it in a file only the user can read, which is then passed on to aoc-cli.
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::template::http::{self, HttpError};
+
+/// Where aoc-cli looks for a session, if none was stored with `cargo auth login`.
+const AOC_CLI_SESSION_VAR: &str = "ADVENT_OF_CODE_SESSION";
+const AOC_CLI_SESSION_FILE: &str = ".adventofcode.session";
+
+fn config_dir() -> Option<PathBuf> {
+    let dir = |var: &str| {
+        env::var_os(var)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+
+    dir("XDG_CONFIG_HOME")
+        .or_else(|| dir("HOME").map(|home| home.join(".config")))
+        .or_else(|| dir("APPDATA"))
+}
+
+/// Path of the stored session, e.g. `~/.config/advent_of_code/session`.
+#[must_use]
+pub fn get_path_for_session() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("advent_of_code").join("session"))
+}
+
+/// The session stored with `cargo auth login`, if any.
+pub fn read_session() -> Option<String> {
+    let session = fs::read_to_string(get_path_for_session()?).ok()?;
+    Some(session.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// The session used for requests and where it comes from: the stored one, or else the one aoc-cli would find.
+pub fn current_session() -> Option<(String, String)> {
+    if let Some(session) = read_session() {
+        let path = get_path_for_session()?;
+        return Some((session, path.display().to_string()));
+    }
+
+    if let Ok(session) = env::var(AOC_CLI_SESSION_VAR) {
+        return Some((
+            session.trim().to_string(),
+            format!("${AOC_CLI_SESSION_VAR}"),
+        ));
+    }
+
+    let path = PathBuf::from(env::var_os("HOME")?).join(AOC_CLI_SESSION_FILE);
+    let session = fs::read_to_string(&path).ok()?;
+    Some((session.trim().to_string(), path.display().to_string()))
+}
+
+/// Parse a session as copied from the browser, with or without the `session=` prefix.
+pub fn parse_session(input: &str) -> Result<String, String> {
+    let session = input.trim();
+    let session = session.strip_prefix("session=").unwrap_or(session);
+
+    if session.is_empty() || !session.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("A session consists of hexadecimal digits, copy it from the `session` cookie of the site.".into());
+    }
+
+    Ok(session.to_string())
+}
+
+/// Write the session to a file only the user can read and write.
+pub fn store_session(path: &Path, session: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)?;
+    // the file may have existed with wider permissions.
+    #[cfg(unix)]
+    fs::set_permissions(path, std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+
+    writeln!(file, "{session}")
+}
+
+/// Whether the server accepts the session, checked with a request to a page that requires logging in.
+pub fn validate(base_url: &str, session: &str) -> Result<bool, HttpError> {
+    let response = http::get(&format!("{base_url}/settings"), Some(session))?;
+    // the server redirects to the login page instead.
+    Ok(response.status == 200)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{parse_session, store_session, validate};
+    use crate::template::http::stub;
+
+    #[test]
+    fn parses_sessions() {
+        assert_eq!(parse_session(" session=53616c74\n"), Ok("53616c74".into()));
+        assert_eq!(parse_session("53616c74"), Ok("53616c74".into()));
+        assert!(parse_session("").is_err());
+        assert!(parse_session("abc\"\ncookie = x").is_err());
+    }
+
+    #[test]
+    fn validates_sessions() {
+        let url = stub::serve(|request| {
+            if request.starts_with("GET /settings ") && request.contains("session=c0ffee") {
+                (200, "settings".into())
+            } else {
+                (302, String::new())
+            }
+        });
+
+        assert!(validate(&url, "c0ffee").unwrap());
+        assert!(!validate(&url, "deadbeef").unwrap());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn restricts_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = env::temp_dir()
+            .join(format!("advent_of_code-{}", std::process::id()))
+            .join("session");
+        store_session(&path, "c0ffee").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "c0ffee\n");
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,254 @@
+/// Module that benchmarks another git revision, to compare it with the working tree.
+/// The revision is checked out into a temporary worktree, which is removed again when dropped.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    process::{self, Command, Output},
+};
+
+use crate::template::{
+    Day, all_days,
+    timings::{PartTiming, Timings},
+};
+
+#[derive(Debug)]
+pub enum GitError {
+    CommandNotFound,
+    BadExitStatus(Output),
+    IO(io::Error),
+}
+
+impl Display for GitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitError::CommandNotFound => write!(f, "git is not present in environment."),
+            GitError::BadExitStatus(output) => write!(
+                f,
+                "git exited with a non-zero status: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            GitError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for GitError {
+    fn from(e: io::Error) -> Self {
+        GitError::IO(e)
+    }
+}
+
+fn git(args: &[&str]) -> Result<Output, GitError> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|_| GitError::CommandNotFound)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(GitError::BadExitStatus(output))
+    }
+}
+
+/// A detached checkout of a revision in the temp directory.
+pub struct Worktree {
+    pub path: PathBuf,
+}
+
+impl Worktree {
+    /// Check out `rev` and copy the puzzle inputs over, as they are usually not committed.
+    pub fn add(rev: &str) -> Result<Self, GitError> {
+        let path = std::env::temp_dir().join(format!("advent-of-code-baseline-{}", process::id()));
+        let path_str = path.to_string_lossy();
+
+        git(&["worktree", "add", "--detach", "--force", &path_str, rev])?;
+        let worktree = Worktree { path };
+        worktree.copy_inputs()?;
+        Ok(worktree)
+    }
+
+    fn copy_inputs(&self) -> Result<(), io::Error> {
+        let target = self.path.join("data/inputs");
+        fs::create_dir_all(&target)?;
+
+        for entry in fs::read_dir("data/inputs")?.flatten() {
+            let destination = target.join(entry.file_name());
+            if entry.path().is_file() && !destination.exists() {
+                fs::copy(entry.path(), destination)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        if let Err(e) = git(&[
+            "worktree",
+            "remove",
+            "--force",
+            &self.path.to_string_lossy(),
+        ]) {
+            eprintln!("Failed to remove worktree \"{}\": {e}", self.path.display());
+        }
+    }
+}
+
+/// Timings of one part in the baseline revision and in the working tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Option<PartTiming>,
+    pub current: Option<PartTiming>,
+}
+
+impl Comparison {
+    /// How many times faster the working tree is, e.g. `2.0` when it takes half the time.
+    pub fn speedup(&self) -> Option<f64> {
+        match (self.baseline, self.current) {
+            (Some(baseline), Some(current)) if current.nanos > 0.0 => {
+                Some(baseline.nanos / current.nanos)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Pair the parts of both runs that have been benchmarked in at least one of them.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<Comparison> {
+    let part = |timings: &Timings, day, part| {
+        timings
+            .data
+            .iter()
+            .find(|t| t.day == day)
+            .and_then(|t| t.part(part))
+            .copied()
+    };
+
+    all_days()
+        .flat_map(|day| (1..=2).map(move |p| (day, p)))
+        .map(|(day, p)| Comparison {
+            day,
+            part: p,
+            baseline: part(baseline, day, p),
+            current: part(current, day, p),
+        })
+        .filter(|c| c.baseline.is_some() || c.current.is_some())
+        .collect()
+}
+
+fn format_speedup(speedup: Option<f64>) -> String {
+    speedup.map_or_else(|| "-".into(), |s| format!("{s:.2}x"))
+}
+
+/// Format the comparison as an aligned table, followed by the speedup of all parts measured in both runs.
+pub fn format_table(comparisons: &[Comparison], rev: &str) -> Vec<String> {
+    let timing = |t: Option<PartTiming>| t.map_or_else(|| "-".into(), |t| t.to_string());
+
+    let mut lines = vec![format!(
+        "{:<4} {:<5} {:>12} {:>12} {:>8}",
+        "Day", "Part", rev, "current", "speedup"
+    )];
+
+    for c in comparisons {
+        lines.push(format!(
+            "{:<4} {:<5} {:>12} {:>12} {:>8}",
+            c.day.to_string(),
+            c.part,
+            timing(c.baseline),
+            timing(c.current),
+            format_speedup(c.speedup())
+        ));
+    }
+
+    let (baseline, current) = comparisons.iter().filter(|c| c.speedup().is_some()).fold(
+        (0.0, 0.0),
+        |(b, c), comparison| {
+            (
+                b + comparison.baseline.map_or(0.0, |t| t.nanos),
+                c + comparison.current.map_or(0.0, |t| t.nanos),
+            )
+        },
+    );
+
+    let total = (current > 0.0).then(|| baseline / current);
+    lines.push(format!(
+        "{:<10} {:>12.2} {:>12.2} {:>8}",
+        "Total (ms)",
+        baseline / 1e6,
+        current / 1e6,
+        format_speedup(total)
+    ));
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, format_table};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+        })
+    }
+
+    fn timings(data: Vec<(u8, Option<PartTiming>, Option<PartTiming>)>) -> Timings {
+        Timings {
+            data: data
+                .into_iter()
+                .map(|(day, part_1, part_2)| Timing {
+                    day: crate::template::Day::new(day).unwrap(),
+                    part_1,
+                    part_2,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn compares_parts() {
+        let baseline = timings(vec![(1, part(4e6), part(1e6)), (2, part(1e6), None)]);
+        let current = timings(vec![(1, part(2e6), part(2e6)), (3, part(1e6), None)]);
+
+        let comparisons = compare(&baseline, &current);
+        assert_eq!(comparisons.len(), 4);
+        assert_eq!(comparisons[0].speedup(), Some(2.0));
+        assert_eq!(comparisons[1].speedup(), Some(0.5));
+        assert_eq!(comparisons[2].day, day!(2));
+        assert_eq!(comparisons[2].speedup(), None);
+        assert_eq!(comparisons[3].baseline, None);
+    }
+
+    #[test]
+    fn formats_table() {
+        let baseline = timings(vec![(1, part(4e6), part(1e6))]);
+        let current = timings(vec![(1, part(2e6), part(2e6))]);
+
+        let lines = format_table(&compare(&baseline, &current), "main");
+        assert_eq!(
+            lines,
+            [
+                "Day  Part          main      current  speedup",
+                "01   1            4.0ms        2.0ms    2.00x",
+                "01   2            1.0ms        2.0ms    0.50x",
+                "Total (ms)         5.00         4.00    1.25x",
+            ]
+        );
+    }
+}
@@ -0,0 +1,262 @@
+/// Module that renders benchmark timings as an SVG chart.
+/// Parts are drawn as horizontal bars on a logarithmic scale, followed by a stacked bar of the day totals.
+use std::{fmt::Write, fs, io, path::Path, time::Duration};
+
+use crate::template::timings::Timings;
+
+pub static CHART_PATH: &str = ".assets/benchmarks.svg";
+
+const WIDTH: f64 = 800.0;
+const LABEL_WIDTH: f64 = 70.0;
+const MARGIN: f64 = 20.0;
+const BAR_HEIGHT: f64 = 8.0;
+const ROW_HEIGHT: f64 = 22.0;
+const AXIS_HEIGHT: f64 = 30.0;
+const TOTAL_HEIGHT: f64 = 60.0;
+
+const TEXT_COLOR: &str = "#888888";
+const GRID_COLOR: &str = "#88888844";
+const PART_COLORS: [&str; 2] = ["#f4c542", "#4aa3df"];
+const STACK_COLORS: [&str; 4] = ["#f4c542", "#4aa3df", "#e0685c", "#5cb85c"];
+
+/// Format nanoseconds like the duration strings in the benchmark table, e.g. "42.7µs".
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Format the label of a decade on the axis in whole units, e.g. "10µs".
+fn format_decade(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{n:.0}ns"),
+        n if n < 1e6 => format!("{:.0}µs", n / 1e3),
+        n if n < 1e9 => format!("{:.0}ms", n / 1e6),
+        n => format!("{:.0}s", n / 1e9),
+    }
+}
+
+/// Logarithmic x-axis spanning whole decades.
+struct Scale {
+    min_exp: i32,
+    max_exp: i32,
+}
+
+impl Scale {
+    fn new(values: &[f64]) -> Self {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(0.0, f64::max);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let (min_exp, max_exp) = if values.is_empty() {
+            (3, 9)
+        } else {
+            (
+                min.max(1.0).log10().floor() as i32,
+                max.max(1.0).log10().ceil() as i32,
+            )
+        };
+
+        Scale {
+            min_exp,
+            max_exp: max_exp.max(min_exp + 1),
+        }
+    }
+
+    fn x(&self, nanos: f64) -> f64 {
+        let span = f64::from(self.max_exp - self.min_exp);
+        let position = (nanos.max(1.0).log10() - f64::from(self.min_exp)) / span;
+        LABEL_WIDTH + position.clamp(0.0, 1.0) * (WIDTH - LABEL_WIDTH - MARGIN)
+    }
+}
+
+/// Render the SVG chart for a set of timings.
+pub fn render(timings: &Timings) -> String {
+    let rows: Vec<_> = timings
+        .data
+        .iter()
+        .map(|t| (t.day, [t.part_nanos(1), t.part_nanos(2)]))
+        .collect();
+
+    let values: Vec<f64> = rows
+        .iter()
+        .flat_map(|(_, parts)| parts.iter().flatten().copied())
+        .collect();
+    let scale = Scale::new(&values);
+
+    #[allow(clippy::cast_precision_loss)]
+    let chart_height = rows.len() as f64 * ROW_HEIGHT;
+    let height = MARGIN + chart_height + AXIS_HEIGHT + TOTAL_HEIGHT;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif" font-size="12" fill="{TEXT_COLOR}">"#
+    );
+
+    // grid lines and axis labels at every decade.
+    let axis_y = MARGIN + chart_height;
+    for exp in scale.min_exp..=scale.max_exp {
+        let nanos = 10_f64.powi(exp);
+        let x = scale.x(nanos);
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{x:.1}" y1="{MARGIN}" x2="{x:.1}" y2="{axis_y}" stroke="{GRID_COLOR}"/>"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle">{}</text>"#,
+            axis_y + 15.0,
+            format_decade(nanos)
+        );
+    }
+
+    for (i, (day, parts)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = MARGIN + i as f64 * ROW_HEIGHT;
+        let _ = writeln!(
+            svg,
+            r#"<text x="0" y="{:.1}">Day {}</text>"#,
+            y + BAR_HEIGHT + 4.0,
+            day.into_inner()
+        );
+
+        for (part, nanos) in parts.iter().enumerate() {
+            let Some(nanos) = nanos else {
+                continue;
+            };
+            #[allow(clippy::cast_precision_loss)]
+            let bar_y = y + part as f64 * (BAR_HEIGHT + 1.0);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{LABEL_WIDTH}" y="{bar_y:.1}" width="{:.1}" height="{BAR_HEIGHT}" fill="{}"><title>Day {} Part {}: {}</title></rect>"#,
+                (scale.x(*nanos) - LABEL_WIDTH).max(1.0),
+                PART_COLORS[part],
+                day.into_inner(),
+                part + 1,
+                format_nanos(*nanos)
+            );
+        }
+    }
+
+    // stacked bar of the total, on a linear scale.
+    let total_nanos = timings.total_millis() * 1e6;
+    let total_y = axis_y + AXIS_HEIGHT;
+    let _ = writeln!(svg, r#"<text x="0" y="{:.1}">Total</text>"#, total_y + 14.0);
+
+    let mut x = LABEL_WIDTH;
+    for (i, timing) in timings.data.iter().enumerate() {
+        if total_nanos <= 0.0 || timing.total_nanos() <= 0.0 {
+            continue;
+        }
+        let width = timing.total_nanos() / total_nanos * (WIDTH - LABEL_WIDTH - MARGIN);
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x:.1}" y="{total_y:.1}" width="{width:.1}" height="20" fill="{}"><title>Day {}: {}</title></rect>"#,
+            STACK_COLORS[i % STACK_COLORS.len()],
+            timing.day.into_inner(),
+            format_nanos(timing.total_nanos())
+        );
+        x += width;
+    }
+
+    let _ = writeln!(
+        svg,
+        r#"<text x="{LABEL_WIDTH}" y="{:.1}">{:.2}ms</text>"#,
+        total_y + 38.0,
+        timings.total_millis()
+    );
+
+    // legend
+    for (part, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let x = WIDTH - MARGIN - 140.0 + part as f64 * 70.0;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x:.1}" y="{:.1}" width="10" height="10" fill="{color}"/><text x="{:.1}" y="{:.1}">Part {}</text>"#,
+            total_y + 28.0,
+            x + 14.0,
+            total_y + 37.0,
+            part + 1
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Render the chart and write it to the assets folder.
+pub fn store(timings: &Timings) -> Result<(), io::Error> {
+    if let Some(dir) = Path::new(CHART_PATH).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(CHART_PATH, render(timings))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Scale, format_decade, format_nanos, render};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 100,
+            stats: None,
+        })
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = Scale::new(&[1500.0, 2e7]);
+        assert_eq!((scale.min_exp, scale.max_exp), (3, 8));
+        assert!((scale.x(1e4) - scale.x(1e3) - (scale.x(1e8) - scale.x(1e7))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn formats_nanos() {
+        assert_eq!(format_nanos(42_700.0), "42.7µs");
+        assert_eq!(format_nanos(1.5e9), "1.5s");
+        assert_eq!(format_decade(100.0), "100ns");
+        assert_eq!(format_decade(1e4), "10µs");
+        assert_eq!(format_decade(1e8), "100ms");
+    }
+
+    #[test]
+    fn renders_bars_per_part() {
+        let timings = Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: part(46_100.0),
+                    part_2: part(42_700.0),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                },
+                Timing {
+                    day: day!(12),
+                    part_1: part(190_500.0),
+                    part_2: None,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                },
+            ],
+        };
+
+        let svg = render(&timings);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<title>Day 1 Part 2: 42.7µs</title>"));
+        assert!(svg.contains("<title>Day 1: 88.8µs</title>"));
+        assert!(svg.contains(">10µs</text>"));
+        assert!(!svg.contains("Day 12 Part 2"));
+        assert_eq!(svg.matches("<title>Day ").count(), 5);
+    }
+}
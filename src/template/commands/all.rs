@@ -1,5 +1,14 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, all_days,
+    run_multi::{format_summary, run_multi},
+};
 
 pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+    let timings = run_multi(&all_days().collect(), is_release, false);
+
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    for line in format_summary(&timings) {
+        println!("{line}");
+    }
 }
@@ -0,0 +1,128 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    auth::{
+        current_session, get_path_for_session, parse_session, read_session, store_session, validate,
+    },
+    http,
+};
+
+pub enum AuthAction {
+    /// Log in with the given session, or one entered at a prompt.
+    Login(Option<String>),
+    Status,
+    Logout,
+}
+
+pub fn handle(action: AuthAction) {
+    match action {
+        AuthAction::Login(session) => login(session),
+        AuthAction::Status => status(),
+        AuthAction::Logout => logout(),
+    }
+}
+
+/// Read the session from stdin, without echoing it in a terminal.
+fn prompt_session() -> String {
+    let is_terminal = io::stdin().is_terminal();
+    let stty = |arg: &str| {
+        let _ = Command::new("stty")
+            .arg(arg)
+            .stdin(Stdio::inherit())
+            .status();
+    };
+
+    if is_terminal {
+        print!("Paste the value of the `session` cookie: ");
+        let _ = io::stdout().flush();
+        stty("-echo");
+    }
+
+    let mut session = String::new();
+    let read = io::stdin().read_line(&mut session);
+
+    if is_terminal {
+        stty("echo");
+        println!();
+    }
+
+    if let Err(e) = read {
+        eprintln!("Failed to read the session: {e}");
+        process::exit(1);
+    }
+    session
+}
+
+fn login(session: Option<String>) {
+    let session = parse_session(&session.unwrap_or_else(prompt_session)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    match validate(&http::base_url(), &session) {
+        Ok(true) => {}
+        Ok(false) => {
+            eprintln!(
+                "The server did not accept the session. It may have expired, log in to the site again to get a new one."
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to validate the session: {e}");
+            process::exit(1);
+        }
+    }
+
+    let Some(path) = get_path_for_session() else {
+        eprintln!("Failed to find a config directory. Set $XDG_CONFIG_HOME or $HOME.");
+        process::exit(1);
+    };
+
+    if let Err(e) = store_session(&path, &session) {
+        eprintln!("Failed to store the session in \"{}\": {e}", path.display());
+        process::exit(1);
+    }
+
+    println!(
+        "🎄 Logged in. Stored the session in \"{}\".",
+        path.display()
+    );
+}
+
+fn status() {
+    let Some((session, source)) = current_session() else {
+        println!("Not logged in. Log in with `cargo auth login`.");
+        process::exit(1);
+    };
+
+    match validate(&http::base_url(), &session) {
+        Ok(true) => println!("🎄 Logged in with the session from \"{source}\"."),
+        Ok(false) => {
+            eprintln!(
+                "The session from \"{source}\" has expired. Log in again with `cargo auth login`."
+            );
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to validate the session from \"{source}\": {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn logout() {
+    match (read_session(), get_path_for_session()) {
+        (Some(_), Some(path)) => {
+            if let Err(e) = fs::remove_file(&path) {
+                eprintln!("Failed to remove \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+            println!("Logged out. Removed \"{}\".", path.display());
+        }
+        _ => println!("Not logged in, there is no stored session."),
+    }
+}
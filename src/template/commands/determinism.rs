@@ -0,0 +1,55 @@
+use std::{collections::HashSet, path::Path, process};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, all_days, determinism,
+    run_multi::{child_commands::run_solution_output, get_path_for_bin},
+};
+
+/// Run the parts of every day `runs` times, each in a fresh process.
+/// As every process seeds the hashers of `HashMap` and `HashSet` differently, iteration order changes between runs.
+pub fn handle(day: Option<Day>, runs: usize) {
+    if runs < 2 {
+        eprintln!("At least 2 runs are needed to compare answers.");
+        process::exit(1);
+    }
+
+    let days: HashSet<Day> = day.map_or_else(|| all_days().collect(), |day| HashSet::from([day]));
+    let mut flagged = 0;
+
+    for day in all_days().filter(|day| days.contains(day)) {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            continue;
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+        let outputs: Result<Vec<_>, _> = (0..runs).map(|_| run_solution_output(day)).collect();
+        let outputs = outputs.unwrap_or_else(|e| {
+            eprintln!("Failed to run day {day}: {e:?}");
+            process::exit(1);
+        });
+
+        let answers: Vec<_> = outputs
+            .iter()
+            .map(|o| determinism::parse_answers(o))
+            .collect();
+        let parts = determinism::collect(&answers);
+
+        if parts.is_empty() {
+            println!("Not solved.");
+        }
+        for line in determinism::format_report(&parts) {
+            println!("{line}");
+        }
+        println!();
+
+        flagged += parts.iter().filter(|p| !p.is_deterministic()).count();
+    }
+
+    if flagged == 0 {
+        println!("All parts gave the same answer in {runs} runs.");
+    } else {
+        println!("{flagged} part(s) gave different answers in {runs} runs.");
+        process::exit(1);
+    }
+}
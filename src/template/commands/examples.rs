@@ -0,0 +1,193 @@
+use std::{fs, process};
+
+use crate::template::{
+    Day,
+    puzzle::{Example, Puzzle, get_path_for_puzzle},
+};
+
+fn get_example_path(day: Day, part: u8) -> String {
+    if part == 1 {
+        format!("data/examples/{day}.txt")
+    } else {
+        format!("data/examples/{day}-{part}.txt")
+    }
+}
+
+fn get_module_path(day: Day) -> String {
+    format!("src/bin/{day}.rs")
+}
+
+/// Whether a file can be written without losing data.
+fn is_writable(path: &str, overwrite: bool) -> bool {
+    overwrite || fs::read_to_string(path).map_or(true, |s| s.trim().is_empty())
+}
+
+/// Format an answer as the expected value of a test assertion.
+/// Integers are written as numbers, so that they match both signed and unsigned parts.
+fn format_expected(answer: &str) -> String {
+    if answer.parse::<i128>().is_ok() {
+        format!("Some({answer})")
+    } else {
+        format!("Some(\"{}\".to_string())", answer.escape_default())
+    }
+}
+
+/// Fill in the expected answer of the test `test_part_{one,two}`.
+/// Assertions that have already been changed from `None` are left untouched.
+fn fill_test(module: &str, part: u8, answer: Option<&str>, has_part_file: bool) -> String {
+    let test_name = if part == 1 {
+        "fn test_part_one()"
+    } else {
+        "fn test_part_two()"
+    };
+
+    let Some(start) = module.find(test_name) else {
+        return module.to_string();
+    };
+    let end = module[start..]
+        .find("\n    }")
+        .map_or(module.len(), |i| start + i);
+
+    let mut body = module[start..end].to_string();
+
+    if has_part_file {
+        body = body.replace(
+            "read_file(\"examples\", DAY)",
+            &format!("read_file_part(\"examples\", DAY, {part})"),
+        );
+    }
+
+    if let Some(answer) = answer {
+        body = body.replacen(
+            "assert_eq!(result, None);",
+            &format!("assert_eq!(result, {});", format_expected(answer)),
+            1,
+        );
+    }
+
+    format!("{}{}{}", &module[..start], body, &module[end..])
+}
+
+fn write_example(day: Day, part: u8, example: &Example, overwrite: bool) -> bool {
+    let Some(input) = &example.input else {
+        return false;
+    };
+
+    let path = get_example_path(day, part);
+
+    if !is_writable(&path, overwrite) {
+        println!(
+            "Skipped example file \"{path}\", it is not empty. Use `--overwrite` to replace it."
+        );
+        // an existing part file should still be used by the tests.
+        return part > 1;
+    }
+
+    match fs::write(&path, input) {
+        Ok(()) => {
+            println!("Wrote example to \"{path}\"");
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(day: Day, overwrite: bool) {
+    let puzzle = match Puzzle::read(day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!(
+                "Failed to read puzzle \"{}\": {e}. Try running `cargo download {day}` first.",
+                get_path_for_puzzle(day)
+            );
+            process::exit(1);
+        }
+    };
+
+    let module_path = get_module_path(day);
+    let mut module = fs::read_to_string(&module_path).ok();
+
+    for part in 1..=2 {
+        if puzzle.part(part).is_none() {
+            continue;
+        }
+
+        let example = puzzle.example(part);
+        let has_part_file = write_example(day, part, &example, overwrite) && part > 1;
+
+        match &example.answer {
+            Some(answer) => println!("Part {part}: expecting {answer}"),
+            None => println!("Part {part}: could not find the expected answer"),
+        }
+
+        if let Some(m) = &module {
+            module = Some(fill_test(m, part, example.answer.as_deref(), has_part_file));
+        }
+    }
+
+    match module {
+        Some(module) => {
+            if let Err(e) = fs::write(&module_path, module) {
+                eprintln!("Failed to update module file: {e}");
+                process::exit(1);
+            }
+            println!("Updated tests in \"{module_path}\"");
+        }
+        None => {
+            println!("Module file \"{module_path}\" not found, skipped updating tests.");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::fill_test;
+
+    const MODULE: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+    #[test]
+    fn fills_numeric_answers() {
+        let module = fill_test(MODULE, 1, Some("13"), false);
+        let module = fill_test(&module, 2, Some("43"), false);
+        assert!(module.contains("assert_eq!(result, Some(13));"));
+        assert!(module.contains("assert_eq!(result, Some(43));"));
+        assert!(!module.contains("read_file_part"));
+    }
+
+    #[test]
+    fn fills_negative_answers() {
+        let module = fill_test(MODULE, 1, Some("-5"), false);
+        assert!(module.contains("assert_eq!(result, Some(-5));"));
+    }
+
+    #[test]
+    fn fills_text_answers() {
+        let module = fill_test(MODULE, 2, Some("abc"), false);
+        assert!(module.contains("assert_eq!(result, None);"));
+        assert!(module.contains("assert_eq!(result, Some(\"abc\".to_string()));"));
+    }
+
+    #[test]
+    fn uses_part_files() {
+        let module = fill_test(MODULE, 2, Some("43"), true);
+        assert_eq!(module.matches("read_file(\"examples\", DAY)").count(), 1);
+        assert!(module.contains("read_file_part(\"examples\", DAY, 2)"));
+    }
+
+    #[test]
+    fn keeps_existing_assertions() {
+        let module = MODULE.replacen(
+            "assert_eq!(result, None);",
+            "assert_eq!(result, Some(1));",
+            1,
+        );
+        let module = fill_test(&module, 1, Some("13"), false);
+        assert!(module.contains("assert_eq!(result, Some(1));"));
+        assert!(!module.contains("Some(13)"));
+    }
+}
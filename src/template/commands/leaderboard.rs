@@ -0,0 +1,68 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::{
+    Day,
+    aoc_cli::{format_wait, get_year},
+    auth::current_session,
+    http,
+    leaderboard::{
+        FETCH_INTERVAL, Leaderboard, fetch, get_path_for_leaderboard,
+        get_path_for_previous_leaderboard, render, render_day, store,
+    },
+};
+
+pub fn handle(id: u64, day: Option<Day>) {
+    let Some(year) = get_year() else {
+        eprintln!("Set $AOC_YEAR to the year of the leaderboard.");
+        process::exit(1);
+    };
+
+    let path = get_path_for_leaderboard(year, id);
+    let age = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+
+    // the server asks to not fetch leaderboards too often, recent fetches are shown from the cache.
+    if let Some(age) = age.filter(|age| *age < FETCH_INTERVAL) {
+        println!(
+            "Showing the leaderboard fetched {} ago. It is fetched again in {}.",
+            format_wait(age),
+            format_wait(FETCH_INTERVAL - age)
+        );
+    } else {
+        let Some((session, _)) = current_session() else {
+            eprintln!("Not logged in. Log in with `cargo auth login`.");
+            process::exit(1);
+        };
+
+        let json = fetch(&http::base_url(), year, id, &session).unwrap_or_else(|e| {
+            eprintln!("Failed to fetch leaderboard {id}: {e}");
+            process::exit(1);
+        });
+
+        if let Err(e) = store(year, id, &json) {
+            eprintln!(
+                "Failed to cache the leaderboard in \"{}\": {e}",
+                path.display()
+            );
+            process::exit(1);
+        }
+    }
+
+    let read = |path: &PathBuf| fs::read_to_string(path).ok();
+    let current = read(&path)
+        .ok_or_else(|| "could not read the cache.".to_string())
+        .and_then(|json| Leaderboard::parse(&json))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to read \"{}\": {e}", path.display());
+            process::exit(1);
+        });
+    let previous = read(&get_path_for_previous_leaderboard(year, id))
+        .and_then(|json| Leaderboard::parse(&json).ok());
+
+    match day {
+        Some(day) => print!("{}", render_day(&current, previous.as_ref(), day)),
+        None => print!("{}", render(&current, previous.as_ref())),
+    }
+}
@@ -0,0 +1,23 @@
+use std::{path::Path, process};
+
+use crate::template::run_multi::child_commands::run_solution_with_args;
+use crate::template::{Day, profiles};
+
+pub fn handle(day: Day, part: u8) {
+    if !(1..=2).contains(&part) {
+        eprintln!("Part must be 1 or 2.");
+        process::exit(1);
+    }
+
+    // the bin minimizes in-process, as it can call its parts directly.
+    let part = part.to_string();
+    if let Err(e) = run_solution_with_args(
+        Path::new("."),
+        day,
+        Some(&profiles::RELEASE),
+        &["--minimize", &part],
+    ) {
+        eprintln!("Failed to run day {day}: {e:?}");
+        process::exit(1);
+    }
+}
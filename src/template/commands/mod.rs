@@ -1,6 +1,15 @@
 pub mod all;
+pub mod auth;
+pub mod determinism;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
+pub mod minimize;
 pub mod read;
+pub mod report;
 pub mod scaffold;
+pub mod scale;
+pub mod search;
 pub mod solve;
 pub mod time;
+pub mod tui;
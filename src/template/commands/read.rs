@@ -1,8 +1,34 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{
+    Day, aoc_cli, markdown,
+    puzzle::{Puzzle, get_path_for_puzzle},
+};
+
+pub fn handle(day: Day, part: Option<u8>) {
+    // render the puzzle offline once it has been downloaded.
+    if let Ok(contents) = fs::read_to_string(get_path_for_puzzle(day)) {
+        let puzzle = Puzzle::parse(&contents);
+
+        let text = match part {
+            None => contents.as_str(),
+            Some(part) => puzzle.part(part).unwrap_or_else(|| {
+                eprintln!("Part {part} of day {day} has not been unlocked yet.");
+                process::exit(1);
+            }),
+        };
+
+        markdown::print_paged(&markdown::render(text, markdown::get_width()));
+        return;
+    }
+
+    if part.is_some() {
+        eprintln!(
+            "Showing a single part requires the puzzle to be downloaded. Try running `cargo download {day}` first."
+        );
+        process::exit(1);
+    }
 
-pub fn handle(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
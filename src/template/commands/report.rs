@@ -0,0 +1,40 @@
+use std::{
+    fs,
+    path::{Component, Path},
+};
+
+use crate::template::report::{ReportData, render};
+
+pub fn handle(output: &str) {
+    let path = Path::new(output);
+
+    // source links are relative to the report, so that the file keeps working when the repository moves.
+    let source_prefix = if path.is_absolute() {
+        std::env::current_dir()
+            .map(|cwd| format!("file://{}/", cwd.display()))
+            .unwrap_or_default()
+    } else {
+        path.parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .map(|_| "../")
+            .collect()
+    };
+
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty())
+        && let Err(e) = fs::create_dir_all(dir)
+    {
+        eprintln!("Failed to create directory \"{}\": {e}", dir.display());
+        std::process::exit(1);
+    }
+
+    let html = render(&ReportData::collect(), &source_prefix);
+    match fs::write(path, html) {
+        Ok(()) => println!("Wrote report to \"{output}\"."),
+        Err(e) => {
+            eprintln!("Failed to write report to \"{output}\": {e}");
+            std::process::exit(1);
+        }
+    }
+}
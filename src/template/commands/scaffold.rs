@@ -1,79 +1,309 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+/// What scaffolding will do with a single file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// The file does not exist (or is empty) and will be written.
+    Create,
+    /// The file has contents and will be replaced, only used with `--overwrite`.
+    Overwrite,
+    /// The file exists and is left as is.
+    Keep,
+    /// The file has contents that would be lost.
+    Conflict,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Action::Create => "create",
+            Action::Overwrite => "overwrite",
+            Action::Keep => "keep",
+            Action::Conflict => "conflict",
+        };
+        f.pad(s)
     }
-    file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// A planned write of a single file.
+#[derive(Clone, Debug)]
+pub struct FileOperation {
+    pub description: &'static str,
+    pub path: String,
+    pub contents: String,
+    pub action: Action,
+}
+
+/// The full set of file operations for scaffolding a day.
+#[derive(Clone, Debug, Default)]
+pub struct Plan {
+    pub operations: Vec<FileOperation>,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn is_empty_file(path: &str) -> bool {
+    fs::read(path).map_or(true, |b| b.iter().all(u8::is_ascii_whitespace))
+}
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+/// Decide what to do with a file. Existing files are never replaced unless `replace` is set,
+/// files that only serve as placeholders are kept as they are.
+fn plan_file(
+    description: &'static str,
+    path: String,
+    contents: String,
+    replace: Option<bool>,
+) -> FileOperation {
+    let action = if !Path::new(&path).exists() {
+        Action::Create
+    } else {
+        match replace {
+            None => Action::Keep,
+            Some(_) if is_empty_file(&path) => Action::Create,
+            Some(true) => Action::Overwrite,
+            Some(false) => Action::Conflict,
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    FileOperation {
+        description,
+        path,
+        contents,
+        action,
+    }
+}
+
+impl Plan {
+    pub fn new(day: Day, overwrite: bool) -> Self {
+        let module = MODULE_TEMPLATE.replace("%DAY_NUMBER%", &day.into_inner().to_string());
+
+        Plan {
+            operations: vec![
+                plan_file(
+                    "module",
+                    format!("src/bin/{day}.rs"),
+                    module,
+                    Some(overwrite),
+                ),
+                plan_file(
+                    "input",
+                    format!("data/inputs/{day}.txt"),
+                    String::new(),
+                    None,
+                ),
+                plan_file(
+                    "example",
+                    format!("data/examples/{day}.txt"),
+                    String::new(),
+                    None,
+                ),
+            ],
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    }
+
+    pub fn conflicts(&self) -> impl Iterator<Item = &FileOperation> {
+        self.operations
+            .iter()
+            .filter(|op| op.action == Action::Conflict)
+    }
+
+    pub fn print(&self) {
+        println!("{ANSI_BOLD}Plan{ANSI_RESET}");
+        for op in &self.operations {
+            println!("  {:<9} {:<7} \"{}\"", op.action, op.description, op.path);
         }
+        println!("---");
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    /// Apply all operations. If one fails, every file that was already written is restored.
+    pub fn execute(&self) -> Result<(), ExecuteError> {
+        let mut backups: Vec<(&str, Option<Vec<u8>>)> = vec![];
+
+        for op in &self.operations {
+            if !matches!(op.action, Action::Create | Action::Overwrite) {
+                continue;
+            }
+
+            let backup = fs::read(&op.path).ok();
+            let result = write_file(&op.path, &op.contents);
+            backups.push((&op.path, backup));
+
+            if let Err(error) = result {
+                let restored = restore(&backups);
+                return Err(ExecuteError { error, restored });
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+
+        Ok(())
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+/// Applying a plan failed on `error`, after which the files in `restored` were restored.
+#[derive(Debug)]
+pub struct ExecuteError {
+    pub error: io::Error,
+    pub restored: Vec<String>,
+}
+
+impl Display for ExecuteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+/// Restore the backups of written files, returning the paths of the files that were changed back.
+fn restore(backups: &[(&str, Option<Vec<u8>>)]) -> Vec<String> {
+    let mut restored = vec![];
+
+    for (path, backup) in backups.iter().rev() {
+        let result = match backup {
+            Some(contents) => fs::write(path, contents),
+            None if Path::new(path).exists() => fs::remove_file(path),
+            // the file was never created.
+            None => continue,
+        };
+
+        match result {
+            Ok(()) => {
+                println!("Restored \"{path}\"");
+                restored.push((*path).to_string());
+            }
+            Err(e) => eprintln!("Failed to restore \"{path}\": {e}"),
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    restored
+}
+
+pub fn handle(day: Day, overwrite: bool, dry_run: bool) {
+    let plan = Plan::new(day, overwrite);
+    plan.print();
+
+    let conflicts: Vec<_> = plan.conflicts().collect();
+    if !conflicts.is_empty() {
+        for op in conflicts {
+            eprintln!(
+                "Refusing to replace {} file \"{}\", it is not empty. Use `--overwrite` to replace it.",
+                op.description, op.path
+            );
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+        process::exit(1);
+    }
+
+    if dry_run {
+        println!("Dry run, no files were changed.");
+        return;
+    }
+
+    if let Err(e) = plan.execute() {
+        eprintln!("Failed to scaffold day {day}: {e}");
+        process::exit(1);
+    }
+
+    for op in &plan.operations {
+        match op.action {
+            Action::Create | Action::Overwrite => {
+                println!("Wrote {} file \"{}\"", op.description, op.path);
+            }
+            _ => {}
         }
     }
 
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{Action, FileOperation, Plan, plan_file};
+
+    fn get_temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}-{name}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn plans_actions() {
+        let dir = get_temp_dir("plan");
+        let path = |name: &str| dir.join(name).to_string_lossy().to_string();
+
+        fs::write(path("empty.txt"), "\n").unwrap();
+        fs::write(path("full.txt"), "input").unwrap();
+
+        let plan =
+            |name: &str, replace| plan_file("file", path(name), String::new(), replace).action;
+
+        assert_eq!(plan("missing.txt", Some(false)), Action::Create);
+        assert_eq!(plan("empty.txt", Some(false)), Action::Create);
+        assert_eq!(plan("full.txt", Some(false)), Action::Conflict);
+        assert_eq!(plan("full.txt", Some(true)), Action::Overwrite);
+        assert_eq!(plan("full.txt", None), Action::Keep);
+        assert_eq!(plan("missing.txt", None), Action::Create);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn restores_files_on_failure() {
+        let dir = get_temp_dir("restore");
+        let path = |name: &str| dir.join(name).to_string_lossy().to_string();
+
+        fs::write(path("existing.txt"), "original").unwrap();
+
+        let plan = Plan {
+            operations: vec![
+                FileOperation {
+                    description: "module",
+                    path: path("existing.txt"),
+                    contents: "replaced".into(),
+                    action: Action::Overwrite,
+                },
+                FileOperation {
+                    description: "input",
+                    path: path("new.txt"),
+                    contents: String::new(),
+                    action: Action::Create,
+                },
+                FileOperation {
+                    description: "example",
+                    path: path("missing_dir/example.txt"),
+                    contents: String::new(),
+                    action: Action::Create,
+                },
+            ],
+        };
+
+        let error = plan.execute().unwrap_err();
+        assert_eq!(error.restored, [path("new.txt"), path("existing.txt")]);
+        assert_eq!(
+            fs::read_to_string(path("existing.txt")).unwrap(),
+            "original"
+        );
+        assert!(!dir.join("new.txt").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+use std::{path::Path, process};
+
+use crate::template::run_multi::child_commands::run_solution_with_args;
+use crate::template::scale::{self, DEFAULT_SIZES};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, profiles};
+
+pub fn handle(day: Day, sizes: Option<Vec<usize>>, seed: u64) {
+    let sizes = sizes.unwrap_or_else(|| DEFAULT_SIZES.to_vec());
+    let sizes = sizes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    let seed = seed.to_string();
+
+    let output = run_solution_with_args(
+        Path::new("."),
+        day,
+        Some(&profiles::RELEASE),
+        &["--time", "--scale", &sizes, "--seed", &seed],
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("Failed to run day {day}: {e:?}");
+        process::exit(1);
+    });
+
+    let samples: Vec<_> = output
+        .iter()
+        .filter_map(|l| scale::parse_sample(l))
+        .collect();
+    if samples.is_empty() {
+        eprintln!("No benchmarks of generated inputs were recorded for day {day}.");
+        process::exit(1);
+    }
+
+    let series = scale::group(&samples);
+
+    println!();
+    println!("{ANSI_BOLD}Scaling{ANSI_RESET}");
+    for line in scale::format_table(&series) {
+        println!("{line}");
+    }
+
+    println!();
+    for line in scale::render_chart(&series, 60, 16) {
+        println!("{line}");
+    }
+}
@@ -0,0 +1,43 @@
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    search::{DocumentKind, Index, collect_documents},
+};
+
+pub fn handle(query: &str, limit: usize) {
+    let index = Index::new(collect_documents());
+    let matches = index.search(query);
+
+    if matches.is_empty() {
+        println!("No matches for \"{query}\".");
+        return;
+    }
+
+    for m in matches.iter().take(limit) {
+        let document = m.document;
+        let year = document.year.map(|y| format!("{y} ")).unwrap_or_default();
+        let kind = match document.kind {
+            DocumentKind::Puzzle => "puzzle",
+            DocumentKind::Solution => "solution",
+        };
+        let title = index
+            .documents
+            .iter()
+            .find(|d| d.year == document.year && d.day == document.day && d.title.is_some())
+            .and_then(|d| d.title.as_deref())
+            .unwrap_or("-");
+
+        println!(
+            "{ANSI_BOLD}{year}Day {}: {title}{ANSI_RESET} {ANSI_ITALIC}({kind}, {}){ANSI_RESET}",
+            document.day, document.path
+        );
+        println!("    {}", m.snippet);
+    }
+
+    if matches.len() > limit {
+        println!("---");
+        println!(
+            "{} more matches, use `--limit` to show them.",
+            matches.len() - limit
+        );
+    }
+}
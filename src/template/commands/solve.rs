@@ -1,21 +1,34 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{Day, viz::VizOutput};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    viz: Option<VizOutput>,
+    verbosity: u8,
+) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+    let mut features = vec![];
 
     if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
+        cmd_args.extend(["--profile".to_string(), "dhat".to_string()]);
+        features.push("dhat-heap");
     } else if release {
         cmd_args.push("--release".to_string());
     }
 
+    // frames are compiled out unless they are shown.
+    if viz.is_some() {
+        features.push("viz");
+    }
+
+    if !features.is_empty() {
+        cmd_args.extend(["--features".to_string(), features.join(",")]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +36,15 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(viz) = viz {
+        cmd_args.push("--viz".to_string());
+        cmd_args.push(viz.to_string());
+    }
+
+    if verbosity > 0 {
+        cmd_args.push(format!("-{}", "v".repeat(verbosity.into())));
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
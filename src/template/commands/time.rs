@@ -1,15 +1,35 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, path::Path, process};
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::baseline::{self, Worktree};
+use crate::template::run_multi::{run_multi, run_multi_in};
+use crate::template::timings::{Timings, TimingsHistory};
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, all_days, benchmark_chart, profiles, readme_benchmarks,
+};
+
+pub use crate::template::readme_benchmarks::VariantDisplay;
+pub use crate::template::timings::MergePolicy;
+
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    merge: MergePolicy,
+    baseline: Option<&str>,
+    compare_profiles: bool,
+    variants: VariantDisplay,
+) {
+    if baseline.is_some() && compare_profiles {
+        eprintln!("`--baseline` cannot be combined with `--profiles`.");
+        process::exit(1);
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // comparisons need fresh runs, so complete days are not skipped.
+            if run_all || baseline.is_some() || compare_profiles {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -21,14 +41,54 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let baseline_timings = baseline.map(|rev| run_baseline(rev, &days_to_run));
+
+    let timings = if compare_profiles {
+        run_profiles(&days_to_run)
+    } else {
+        run_multi(&days_to_run, true, true)
+    };
+
+    if let (Some(rev), Some(baseline_timings)) = (baseline, &baseline_timings) {
+        println!();
+        println!("{ANSI_BOLD}Comparison{ANSI_RESET}");
+        for line in baseline::format_table(&baseline::compare(baseline_timings, &timings), rev) {
+            println!("{line}");
+        }
+    }
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
+        let merged_timings = stored_timings.merge(&timings, merge);
+
+        let changes = stored_timings.changes(&merged_timings);
+        println!();
+        if changes.is_empty() {
+            println!("No stored benchmarks changed.");
+        } else {
+            println!("{ANSI_BOLD}Changes:{ANSI_RESET}");
+            for change in &changes {
+                println!("  {change}");
+            }
+        }
+
         merged_timings.store_file().unwrap();
 
+        let mut history = TimingsHistory::read_from_file();
+        history.push(timings);
+        if let Err(e) = history.store_file() {
+            eprintln!("Failed to store benchmark history: {e}");
+        }
+
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        let chart_path = match benchmark_chart::store(&merged_timings) {
+            Ok(()) => Some(benchmark_chart::CHART_PATH),
+            Err(e) => {
+                eprintln!("Failed to store benchmark chart: {e}");
+                None
+            }
+        };
+
+        match readme_benchmarks::update(merged_timings, chart_path, variants) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -38,3 +98,43 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+/// Benchmark the days in a temporary worktree of `rev`, exiting if it cannot be checked out.
+fn run_baseline(rev: &str, days_to_run: &HashSet<Day>) -> Timings {
+    println!("{ANSI_BOLD}Baseline: {rev}{ANSI_RESET}");
+    println!();
+
+    let worktree = Worktree::add(rev).unwrap_or_else(|e| {
+        eprintln!("Failed to check out \"{rev}\": {e}");
+        process::exit(1);
+    });
+
+    let timings = run_multi_in(&worktree.path, days_to_run, Some(&profiles::RELEASE), true);
+
+    println!();
+    println!("{ANSI_BOLD}Working tree{ANSI_RESET}");
+    println!();
+
+    timings
+}
+
+/// Benchmark the days with every build profile and return the timings of the fastest one per day.
+fn run_profiles(days_to_run: &HashSet<Day>) -> Timings {
+    let runs: Vec<_> = profiles::PROFILES
+        .iter()
+        .map(|profile| {
+            println!("{ANSI_BOLD}Profile: {}{ANSI_RESET}", profile.name);
+            println!();
+            let timings = run_multi_in(Path::new("."), days_to_run, Some(profile), true);
+            println!();
+            (*profile, timings)
+        })
+        .collect();
+
+    println!("{ANSI_BOLD}Profiles{ANSI_RESET}");
+    for line in profiles::format_table(&runs) {
+        println!("{line}");
+    }
+
+    profiles::best(&runs)
+}
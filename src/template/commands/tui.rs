@@ -0,0 +1,261 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, IsTerminal, Read, Write},
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    Day, aoc_cli,
+    commands::{solve, time},
+    dashboard::{self, Key},
+    examples::get_path_for_manifest,
+    markdown,
+    puzzle::get_path_for_puzzle,
+};
+
+/// Puts the terminal in raw mode on the alternate screen, restoring it when dropped.
+/// Uses `stty`, so that no terminal library is needed.
+struct Terminal {
+    settings: String,
+}
+
+impl Terminal {
+    fn enter() -> Result<Self, String> {
+        let settings = stty(&["-g"])?;
+        let terminal = Terminal {
+            settings: settings.trim().to_string(),
+        };
+        terminal.resume()?;
+        Ok(terminal)
+    }
+
+    fn resume(&self) -> Result<(), String> {
+        stty(&["raw", "-echo"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush().map_err(|e| e.to_string())
+    }
+
+    /// Leave the alternate screen temporarily, e.g. to run a command with its regular output.
+    fn suspend(&self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.settings]);
+    }
+
+    fn draw(lines: &[String]) {
+        // raw mode does not translate newlines, so every line returns the carriage itself.
+        print!("\x1b[H\x1b[2J{}", lines.join("\r\n"));
+        let _ = io::stdout().flush();
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        self.suspend();
+    }
+}
+
+fn stty(args: &[&str]) -> Result<String, String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .map_err(|_| "stty is not present in environment.".to_string())?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+fn read_keys() -> Vec<Key> {
+    let mut buffer = [0; 16];
+    match io::stdin().read(&mut buffer) {
+        Ok(0) | Err(_) => vec![Key::Interrupt],
+        Ok(n) => dashboard::parse_keys(&buffer[..n]),
+    }
+}
+
+enum View {
+    Days,
+    Puzzle { lines: Vec<String>, scroll: usize },
+}
+
+struct App {
+    terminal: Terminal,
+    view: View,
+    selected: usize,
+    tests: HashMap<Day, bool>,
+    message: String,
+}
+
+impl App {
+    fn day(&self) -> Day {
+        Day::new(u8::try_from(self.selected + 1).unwrap_or(1)).unwrap()
+    }
+
+    fn height() -> usize {
+        markdown::terminal_size().map_or(24, |(rows, _)| rows)
+    }
+
+    fn draw(&self) {
+        match &self.view {
+            View::Days => {
+                let rows = dashboard::collect(&self.tests);
+                let lines =
+                    dashboard::render(&rows, self.selected, aoc_cli::get_year(), &self.message);
+                Terminal::draw(&lines);
+            }
+            View::Puzzle { lines, scroll } => {
+                Terminal::draw(&dashboard::render_puzzle(lines, *scroll, Self::height()));
+            }
+        }
+    }
+
+    /// Run an action with the regular terminal, then wait for a key press before returning to the dashboard.
+    fn run(&mut self, action: impl FnOnce() -> String) {
+        self.terminal.suspend();
+        println!();
+        self.message = action();
+        println!("\n{}", self.message);
+        println!("Press any key to return to the dashboard.");
+
+        if let Err(e) = self.terminal.resume() {
+            self.message = e;
+        }
+        read_keys();
+    }
+
+    fn cargo_test(day: Day, filter: Option<&str>) -> bool {
+        let day = day.to_string();
+        let mut args = vec!["test", "--bin", &day];
+        args.extend(filter);
+
+        Command::new("cargo")
+            .args(&args)
+            .status()
+            .is_ok_and(|status| status.success())
+    }
+
+    fn open_puzzle(&mut self) {
+        let day = self.day();
+        match fs::read_to_string(get_path_for_puzzle(day)) {
+            Ok(contents) => {
+                let width = markdown::get_width();
+                self.view = View::Puzzle {
+                    lines: markdown::render(&contents, width),
+                    scroll: 0,
+                };
+            }
+            Err(_) => {
+                self.message = format!(
+                    "The puzzle of day {day} has not been downloaded. Try running `cargo download {day}`."
+                );
+            }
+        }
+    }
+
+    /// Handle a key press, returning `false` to quit.
+    fn handle(&mut self, key: Key) -> bool {
+        let day = self.day();
+        let page = Self::height().saturating_sub(2).max(1);
+
+        match (&mut self.view, key) {
+            (_, Key::Interrupt) => return false,
+            (View::Puzzle { .. }, Key::Escape | Key::Char('q')) => self.view = View::Days,
+            (View::Puzzle { lines, scroll }, key) => {
+                let max = lines.len().saturating_sub(1);
+                *scroll = match key {
+                    Key::Up | Key::Char('k') => scroll.saturating_sub(1),
+                    Key::Down | Key::Char('j') => (*scroll + 1).min(max),
+                    Key::PageUp | Key::Char('b') => scroll.saturating_sub(page),
+                    Key::PageDown | Key::Char(' ') => (*scroll + page).min(max),
+                    _ => *scroll,
+                };
+            }
+            (View::Days, Key::Escape | Key::Char('q')) => return false,
+            (View::Days, Key::Up | Key::Char('k')) => {
+                self.selected = self.selected.saturating_sub(1)
+            }
+            (View::Days, Key::Down | Key::Char('j')) => {
+                self.selected = (self.selected + 1).min(dashboard::collect(&self.tests).len() - 1);
+            }
+            (View::Days, Key::Char('t')) => {
+                let mut passed = false;
+                self.run(|| {
+                    passed = Self::cargo_test(day, None);
+                    format!(
+                        "Tests of day {day} {}.",
+                        if passed { "passed" } else { "failed" }
+                    )
+                });
+                self.tests.insert(day, passed);
+            }
+            (View::Days, Key::Char('e')) => {
+                // example tests are generated from the manifest, and named after the part.
+                let manifest = get_path_for_manifest(day);
+                if fs::exists(&manifest).unwrap_or(false) {
+                    self.run(|| {
+                        let passed = Self::cargo_test(day, Some("tests::part_"));
+                        format!(
+                            "Examples of day {day} {}.",
+                            if passed { "passed" } else { "failed" }
+                        )
+                    });
+                } else {
+                    self.message =
+                        format!("Day {day} has no examples, \"{manifest}\" does not exist.");
+                }
+            }
+            (View::Days, Key::Char('r' | '\n')) => self.run(|| {
+                solve::handle(day, true, false, None, None, 0);
+                format!("Ran day {day} on the real input.")
+            }),
+            (View::Days, Key::Char('s')) => self.run(|| {
+                time::handle(
+                    Some(day),
+                    false,
+                    true,
+                    time::MergePolicy::default(),
+                    None,
+                    false,
+                    time::VariantDisplay::default(),
+                );
+                format!("Stored the benchmark of day {day}.")
+            }),
+            (View::Days, Key::Char('p')) => self.open_puzzle(),
+            (View::Days, _) => {}
+        }
+
+        true
+    }
+}
+
+pub fn handle() {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        eprintln!("The dashboard needs an interactive terminal.");
+        process::exit(1);
+    }
+
+    let terminal = Terminal::enter().unwrap_or_else(|e| {
+        eprintln!("Failed to set up the terminal: {e}");
+        process::exit(1);
+    });
+
+    let mut app = App {
+        terminal,
+        view: View::Days,
+        selected: 0,
+        tests: HashMap::new(),
+        message: String::new(),
+    };
+
+    loop {
+        app.draw();
+        if !read_keys().into_iter().all(|key| app.handle(key)) {
+            break;
+        }
+    }
+}
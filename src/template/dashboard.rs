@@ -0,0 +1,229 @@
+/// State and rendering of the `tui` dashboard. The terminal itself is handled by the `tui` command.
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, all_days, aoc_cli::get_input_path, run_multi::get_path_for_bin,
+    submissions::Submissions, timings::Timings,
+};
+
+const ANSI_REVERSE: &str = "\x1b[7m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// Everything the dashboard shows about a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayRow {
+    pub day: Day,
+    pub scaffolded: bool,
+    pub input: bool,
+    /// Whether the tests passed when they were last run from the dashboard.
+    pub tests: Option<bool>,
+    pub stars: u8,
+    /// Stored benchmark time of both parts.
+    pub nanos: Option<f64>,
+}
+
+/// Read the status of every day from the repository and the data files.
+pub fn collect(tests: &HashMap<Day, bool>) -> Vec<DayRow> {
+    let submissions = Submissions::read_from_file();
+    let timings = Timings::read_from_file();
+
+    all_days()
+        .map(|day| DayRow {
+            day,
+            scaffolded: Path::new(&get_path_for_bin(day)).exists(),
+            input: Path::new(&get_input_path(day)).exists(),
+            tests: tests.get(&day).copied(),
+            stars: (1..=2)
+                .map(|part| u8::from(submissions.has_star(day, part)))
+                .sum(),
+            nanos: timings
+                .data
+                .iter()
+                .find(|t| t.day == day)
+                .map(|t| t.total_nanos())
+                .filter(|nanos| *nanos > 0.0),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Escape,
+    Interrupt,
+    Char(char),
+}
+
+/// Parse the bytes read from a terminal in raw mode into key presses.
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = vec![];
+    let mut rest = bytes;
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let (key, consumed) = match (byte, tail) {
+            (0x1b, [b'[', b'A', ..]) => (Key::Up, 3),
+            (0x1b, [b'[', b'B', ..]) => (Key::Down, 3),
+            (0x1b, [b'[', b'5', b'~', ..]) => (Key::PageUp, 4),
+            (0x1b, [b'[', b'6', b'~', ..]) => (Key::PageDown, 4),
+            // skip other escape sequences, e.g. left and right arrows.
+            (0x1b, [b'[', seq @ ..]) => {
+                let len = seq
+                    .iter()
+                    .position(u8::is_ascii_alphabetic)
+                    .map_or(seq.len(), |i| i + 1);
+                rest = &tail[1 + len..];
+                continue;
+            }
+            (0x1b, _) => (Key::Escape, 1),
+            (0x03, _) => (Key::Interrupt, 1),
+            (b'\r' | b'\n', _) => (Key::Char('\n'), 1),
+            (byte, _) if byte.is_ascii() => (Key::Char(char::from(byte)), 1),
+            _ => {
+                rest = tail;
+                continue;
+            }
+        };
+
+        keys.push(key);
+        rest = &rest[consumed..];
+    }
+
+    keys
+}
+
+fn mark(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "✔",
+        Some(false) => "✖",
+        None => "?",
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+pub const HELP: &str = "↑/↓ select  t tests  e examples  r run  p puzzle  s time --store  q quit";
+
+/// Render the list of days, the selected one highlighted, followed by the last message and the key bindings.
+pub fn render(rows: &[DayRow], selected: usize, year: Option<u16>, message: &str) -> Vec<String> {
+    let title = year.map_or_else(
+        || "Advent of Code".into(),
+        |y| format!("Advent of Code {y}"),
+    );
+    let mut lines = vec![
+        format!("{ANSI_BOLD}🎄 {title}{ANSI_RESET}"),
+        String::new(),
+        format!(
+            "{ANSI_BOLD}{:<5} {:<5} {:<5} {:<5} {:<5} {:>10}{ANSI_RESET}",
+            "Day", "Bin", "Input", "Tests", "Stars", "Time"
+        ),
+    ];
+
+    for (i, row) in rows.iter().enumerate() {
+        let stars = format!(
+            "{}{}",
+            "★".repeat(row.stars.into()),
+            "☆".repeat((2 - row.stars).into())
+        );
+        let line = format!(
+            "{:<5} {:<5} {:<5} {:<5} {:<5} {:>10}",
+            row.day.to_string(),
+            mark(Some(row.scaffolded)),
+            mark(Some(row.input)),
+            mark(row.tests),
+            stars,
+            row.nanos.map_or_else(|| "-".into(), format_nanos)
+        );
+
+        lines.push(if i == selected {
+            format!("{ANSI_REVERSE}{line}{ANSI_RESET}")
+        } else {
+            line
+        });
+    }
+
+    lines.push(String::new());
+    lines.push(message.to_string());
+    lines.push(format!("{ANSI_DIM}{HELP}{ANSI_RESET}"));
+    lines
+}
+
+/// Render the visible part of a puzzle, starting at line `scroll`, to fit in `height` rows.
+pub fn render_puzzle(puzzle: &[String], scroll: usize, height: usize) -> Vec<String> {
+    let rows = height.saturating_sub(1).max(1);
+    let mut lines: Vec<String> = puzzle.iter().skip(scroll).take(rows).cloned().collect();
+    lines.resize(rows, String::new());
+
+    let end = (scroll + rows).min(puzzle.len());
+    lines.push(format!(
+        "{ANSI_DIM}lines {}-{end} of {}  ↑/↓ scroll  PgUp/PgDn page  q back{ANSI_RESET}",
+        (scroll + 1).min(end),
+        puzzle.len()
+    ));
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayRow, Key, parse_keys, render, render_puzzle};
+    use crate::day;
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(
+            parse_keys(b"\x1b[Aj\x1b[C\x1b[6~q\x1b\x03\r"),
+            [
+                Key::Up,
+                Key::Char('j'),
+                Key::PageDown,
+                Key::Char('q'),
+                Key::Escape,
+                Key::Interrupt,
+                Key::Char('\n'),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_days() {
+        let row = |day, stars, tests| DayRow {
+            day,
+            scaffolded: true,
+            input: stars > 0,
+            tests,
+            stars,
+            nanos: (stars > 0).then_some(1.5e6),
+        };
+        let lines = render(
+            &[row(day!(1), 2, Some(true)), row(day!(2), 0, None)],
+            1,
+            Some(2025),
+            "Tests of day 01 passed.",
+        );
+
+        assert_eq!(lines[3], "01    ✔     ✔     ✔     ★★         1.5ms");
+        assert_eq!(
+            lines[4],
+            "\x1b[7m02    ✔     ✖     ?     ☆☆             -\x1b[0m"
+        );
+        assert_eq!(lines[6], "Tests of day 01 passed.");
+    }
+
+    #[test]
+    fn renders_puzzle_page() {
+        let puzzle: Vec<String> = (1..=10).map(|i| format!("line {i}")).collect();
+        let lines = render_puzzle(&puzzle, 8, 5);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "line 9");
+        assert_eq!(lines[2], "");
+        assert!(lines[4].contains("lines 9-10 of 10"));
+    }
+}
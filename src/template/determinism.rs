@@ -0,0 +1,210 @@
+/// Module that compares the answers of repeated runs of a solution, to find parts that depend on e.g. `HashMap` iteration order.
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// Remove ANSI escape sequences, e.g. the bold formatting of answers.
+fn strip_ansi(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+/// The answer of every part (variant) printed by a run of a solution, e.g. `("Part 2 (lp)", "33")`.
+/// Durations are dropped, multi-line answers are joined, and panics or errors count as answers too.
+pub fn parse_answers(output: &[String]) -> Vec<(String, String)> {
+    let mut answers: Vec<(String, String)> = vec![];
+    let mut multi_line: Option<usize> = None;
+
+    for line in output {
+        // intermediate output is overwritten with a carriage return.
+        let line = strip_ansi(line.rsplit('\r').next().unwrap_or_default());
+
+        let Some((label, text)) = line
+            .split_once(": ")
+            .filter(|(label, _)| label.starts_with("Part "))
+        else {
+            if let Some(index) = multi_line {
+                let answer = &mut answers[index].1;
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(&line);
+            }
+            continue;
+        };
+
+        let answer = match text.trim_end().rsplit_once(" (") {
+            Some((answer, duration)) if duration.ends_with(')') => answer,
+            _ => text,
+        }
+        .trim_end();
+
+        if answer == "▼" {
+            multi_line = Some(answers.len());
+            answers.push((label.to_string(), String::new()));
+        } else {
+            multi_line = None;
+            answers.push((label.to_string(), answer.to_string()));
+        }
+    }
+
+    answers
+}
+
+/// All answers of one part (variant) over several runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartAnswers {
+    pub label: String,
+    /// Every distinct answer, with the (1-based) runs that produced it.
+    pub answers: Vec<(String, Vec<usize>)>,
+}
+
+impl PartAnswers {
+    pub fn is_deterministic(&self) -> bool {
+        self.answers.len() <= 1
+    }
+}
+
+/// Group the answers of all runs by part. A part missing from a run counts as the answer "missing".
+pub fn collect(runs: &[Vec<(String, String)>]) -> Vec<PartAnswers> {
+    let mut parts: Vec<PartAnswers> = vec![];
+
+    for (label, _) in runs.iter().flatten() {
+        if !parts.iter().any(|p| p.label == *label) {
+            parts.push(PartAnswers {
+                label: label.clone(),
+                answers: vec![],
+            });
+        }
+    }
+
+    for part in &mut parts {
+        for (i, run) in runs.iter().enumerate() {
+            let answer = run
+                .iter()
+                .find(|(label, _)| *label == part.label)
+                .map_or("missing", |(_, answer)| answer.as_str());
+
+            match part.answers.iter_mut().find(|(a, _)| a == answer) {
+                Some((_, runs)) => runs.push(i + 1),
+                None => part.answers.push((answer.to_string(), vec![i + 1])),
+            }
+        }
+    }
+
+    parts
+}
+
+fn format_runs(runs: &[usize]) -> String {
+    let runs: Vec<String> = runs.iter().map(ToString::to_string).collect();
+    format!(
+        "run{} {}",
+        if runs.len() == 1 { "" } else { "s" },
+        runs.join(", ")
+    )
+}
+
+/// Describe every part, listing which runs produced which answer for parts whose answer changed.
+pub fn format_report(parts: &[PartAnswers]) -> Vec<String> {
+    let mut lines = vec![];
+
+    for part in parts {
+        match part.answers.as_slice() {
+            [(answer, _)] if !answer.contains('\n') => {
+                lines.push(format!("{}: deterministic ({answer})", part.label));
+            }
+            [_] => lines.push(format!("{}: deterministic", part.label)),
+            answers => {
+                lines.push(format!(
+                    "{}: {ANSI_BOLD}{} different answers{ANSI_RESET}",
+                    part.label,
+                    answers.len()
+                ));
+                for (answer, runs) in answers {
+                    lines.push(format!(
+                        "  {} ({})",
+                        answer.replace('\n', "\n  "),
+                        format_runs(runs)
+                    ));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{collect, format_report, parse_answers};
+
+    fn output(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse_answers(&output(&[
+            "Part 1: \x1b[1m42\x1b[0m (1.0ms)",
+            "Part 2 (lp): 7 > benching\rPart 2 (lp): \x1b[1m7\x1b[0m (3.0ms @ 10 samples)",
+            "Part 2 (bnb): ✖             ",
+        ]));
+        assert_eq!(
+            answers,
+            [
+                ("Part 1".to_string(), "42".to_string()),
+                ("Part 2 (lp)".to_string(), "7".to_string()),
+                ("Part 2 (bnb)".to_string(), "✖".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_multi_line_answers() {
+        let answers = parse_answers(&output(&[
+            "Part 1: ▼  (1.0ms)",
+            "#..",
+            ".#.",
+            "Part 2: 3 (1.0ms)",
+        ]));
+        assert_eq!(answers[0].1, "#..\n.#.");
+        assert_eq!(answers[1].1, "3");
+    }
+
+    #[test]
+    fn groups_answers_by_run() {
+        let run = |a: &str| {
+            vec![
+                ("Part 1".to_string(), "1".to_string()),
+                ("Part 2".to_string(), a.to_string()),
+            ]
+        };
+        let parts = collect(&[run("5"), run("6"), run("5")]);
+
+        assert!(parts[0].is_deterministic());
+        assert!(!parts[1].is_deterministic());
+        assert_eq!(
+            parts[1].answers,
+            [("5".to_string(), vec![1, 3]), ("6".to_string(), vec![2])]
+        );
+        assert_eq!(
+            format_report(&parts),
+            [
+                "Part 1: deterministic (1)",
+                "Part 2: \x1b[1m2 different answers\x1b[0m",
+                "  5 (runs 1, 3)",
+                "  6 (run 2)",
+            ]
+        );
+    }
+}
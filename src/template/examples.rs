@@ -0,0 +1,282 @@
+/// Module that reads example manifests from `data/examples/NN.json`.
+///
+/// A manifest lists any number of examples per part, each with its expected answer:
+/// ```json
+/// {
+///     "part_one": [
+///         { "name": "example", "file": "01.txt", "answer": 3 },
+///         { "name": "single rotation", "input": "R1000\n", "answer": "0" }
+///     ],
+///     "part_two": [{ "file": "01.txt", "answer": 6, "params": { "start": 50 } }]
+/// }
+/// ```
+/// `file` is relative to `data/examples/`, `input` contains the example inline.
+/// An `answer` of `null` expects the part to return `None`, `params` are passed to parts that take
+/// [puzzle parameters](crate::template::params).
+/// JSON numbers are read as floats, so answers of 2^53 and above must be strings, e.g. `"12345678901234567"`.
+use std::{collections::HashMap, env, fs, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day,
+    params::{self, RawParams, Solver},
+};
+
+/// A single example of a manifest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    pub name: Option<String>,
+    pub input: ExampleInput,
+    pub answer: Option<String>,
+    pub params: RawParams,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExampleInput {
+    File(String),
+    Inline(String),
+}
+
+/// All examples of a day, grouped by part.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub part_one: Vec<Example>,
+    pub part_two: Vec<Example>,
+}
+
+#[must_use]
+pub fn get_path_for_manifest(day: Day) -> String {
+    format!("data/examples/{day}.json")
+}
+
+impl Manifest {
+    /// Read the manifest of a day. If not present, returns an empty manifest.
+    pub fn read(day: Day) -> Result<Self, String> {
+        let cwd = env::current_dir().map_err(|e| e.to_string())?;
+        match fs::read_to_string(cwd.join(get_path_for_manifest(day))) {
+            Ok(s) => Manifest::try_from(s),
+            Err(_) => Ok(Manifest::default()),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> &[Example] {
+        match part {
+            1 => &self.part_one,
+            2 => &self.part_two,
+            _ => &[],
+        }
+    }
+}
+
+impl Example {
+    /// Load the input of this example.
+    pub fn read_input(&self) -> Result<String, String> {
+        match &self.input {
+            ExampleInput::Inline(s) => Ok(s.clone()),
+            ExampleInput::File(path) => {
+                let cwd = env::current_dir().map_err(|e| e.to_string())?;
+                let filepath = cwd.join("data").join("examples").join(path);
+                fs::read_to_string(&filepath)
+                    .map_err(|e| format!("could not open example file {}: {e}", filepath.display()))
+            }
+        }
+    }
+
+    /// A human-readable label for error messages.
+    pub fn label(&self, index: usize) -> String {
+        match &self.name {
+            Some(name) => format!("\"{name}\""),
+            None => format!("#{index}"),
+        }
+    }
+}
+
+/// Run a part against an example of the manifest and assert that it returns the expected answer.
+/// This is called by the tests that [`example_tests!`](crate::example_tests) generates.
+pub fn check<M, S: Solver<M>>(day: Day, part: u8, index: usize, solver: S) {
+    let manifest = Manifest::read(day).unwrap_or_else(|e| panic!("invalid example manifest: {e}"));
+    let example = manifest
+        .part(part)
+        .get(index)
+        .unwrap_or_else(|| panic!("example #{index} of part {part} not found in manifest"));
+
+    let input = example.read_input().unwrap();
+    let params = S::parse_params(&example.params).unwrap_or_else(|e| {
+        panic!(
+            "day {day} part {part}, example {}: {e}",
+            example.label(index)
+        )
+    });
+    let result = solver.solve(&input, &params).map(|r| r.to_string());
+
+    assert_eq!(
+        result,
+        example.answer,
+        "day {day} part {part}, example {}",
+        example.label(index)
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integers from 2^53 on can not be represented exactly by a JSON number.
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+fn format_answer(value: &JsonValue) -> Result<Option<String>, String> {
+    match value {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(s) => Ok(Some(s.clone())),
+        JsonValue::Number(n) if n.abs() >= MAX_EXACT_INTEGER => Err(format!(
+            "Expected example.answer {n:.0} to be a string, as numbers this large are rounded. Quote the answer instead."
+        )),
+        JsonValue::Number(n) if n.fract() == 0.0 => Ok(Some(format!("{n:.0}"))),
+        JsonValue::Number(n) => Ok(Some(n.to_string())),
+        _ => Err("Expected example.answer to be null, a number or a string.".into()),
+    }
+}
+
+impl TryFrom<&JsonValue> for Example {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected example to be a JSON object.")?;
+
+        let name = match json.get("name") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected example.name to be a string.")?
+                    .clone(),
+            ),
+        };
+
+        let input = match (json.get("file"), json.get("input")) {
+            (Some(JsonValue::String(path)), None) => ExampleInput::File(path.clone()),
+            (None, Some(JsonValue::String(input))) => ExampleInput::Inline(input.clone()),
+            _ => {
+                return Err(
+                    "Expected example to have either a `file` or an `input` string.".into(),
+                );
+            }
+        };
+
+        let answer = format_answer(json.get("answer").unwrap_or(&JsonValue::Null))?;
+
+        let params = match json.get("params") {
+            None => RawParams::new(),
+            Some(v) => params::from_json(v).map_err(|e| format!("example.params: {e}"))?,
+        };
+
+        Ok(Example {
+            name,
+            input,
+            answer,
+            params,
+        })
+    }
+}
+
+impl TryFrom<String> for Manifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let part = |key: &str| -> Result<Vec<Example>, String> {
+            match json.get(key) {
+                None => Ok(vec![]),
+                Some(v) => v
+                    .get::<Vec<JsonValue>>()
+                    .ok_or(format!("expected `json.{key}` to be an array."))?
+                    .iter()
+                    .map(Example::try_from)
+                    .collect(),
+            }
+        };
+
+        Ok(Manifest {
+            part_one: part("part_one")?,
+            part_two: part("part_two")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Generates one test per example listed in the manifest `data/examples/NN.json`.
+///
+/// Must be called inside the test module of a solution, with `part_one` and `part_two` in scope.
+/// The tests are generated by the build script, which re-runs whenever a manifest changes.
+#[macro_export]
+macro_rules! example_tests {
+    ($day:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/examples/", $day, ".rs"));
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ExampleInput, Manifest};
+
+    #[test]
+    fn parses_manifest() {
+        let json = r#"{
+            "part_one": [
+                { "name": "example", "file": "01.txt", "answer": 3 },
+                { "input": "R1000\n", "answer": "abc", "params": { "start": 50 } }
+            ],
+            "part_two": [{ "file": "01-2.txt", "answer": null }]
+        }"#;
+        let manifest = Manifest::try_from(json.to_string()).unwrap();
+
+        assert_eq!(manifest.part_one.len(), 2);
+        assert_eq!(manifest.part_one[0].name, Some("example".into()));
+        assert_eq!(
+            manifest.part_one[0].input,
+            ExampleInput::File("01.txt".into())
+        );
+        assert_eq!(manifest.part_one[0].answer, Some("3".into()));
+        assert_eq!(
+            manifest.part_one[1].input,
+            ExampleInput::Inline("R1000\n".into())
+        );
+        assert_eq!(manifest.part_one[1].answer, Some("abc".into()));
+        assert_eq!(manifest.part_one[1].params["start"], "50");
+        assert_eq!(manifest.part_two[0].answer, None);
+    }
+
+    #[test]
+    fn rejects_inexact_numeric_answers() {
+        let json = r#"{ "part_one": [{ "file": "01.txt", "answer": 12345678901234567 }] }"#;
+        let error = Manifest::try_from(json.to_string()).unwrap_err();
+        assert!(error.contains("to be a string"), "{error}");
+
+        let json = r#"{ "part_one": [{ "file": "01.txt", "answer": "12345678901234567" }] }"#;
+        let manifest = Manifest::try_from(json.to_string()).unwrap();
+        assert_eq!(
+            manifest.part_one[0].answer,
+            Some("12345678901234567".into())
+        );
+    }
+
+    #[test]
+    fn handles_missing_parts() {
+        let manifest = Manifest::try_from("{}".to_string()).unwrap();
+        assert_eq!(manifest, Manifest::default());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_missing_input() {
+        let json = r#"{ "part_one": [{ "answer": 3 }] }"#;
+        Manifest::try_from(json.to_string()).unwrap();
+    }
+}
@@ -0,0 +1,150 @@
+/// Minimal HTTP client for the few requests aoc-cli does not make, which shells out to `curl`.
+use std::{
+    env,
+    fmt::Display,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Identifies the tool to the server, as requested by its maintainer.
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum HttpError {
+    CurlNotFound,
+    Failed(String),
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::CurlNotFound => write!(f, "curl is not present in environment."),
+            HttpError::Failed(e) => write!(f, "request failed: {e}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The server to send requests to. Can be overridden with `AOC_BASE_URL`, e.g. to use a local stand-in.
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .unwrap_or_else(|_| "https://adventofcode.com".into())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Send a GET request, authenticated with a session cookie if given. Redirects are not followed.
+pub fn get(url: &str, session: Option<&str>) -> Result<Response, HttpError> {
+    let mut child = Command::new("curl")
+        // the options are read from stdin, so that the session never shows up in the list of processes.
+        .args([
+            "--silent",
+            "--show-error",
+            "--max-time",
+            "30",
+            "--config",
+            "-",
+        ])
+        .args(["--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| HttpError::CurlNotFound)?;
+
+    let mut config = format!("user-agent = \"{USER_AGENT}\"\n");
+    if let Some(session) = session {
+        config.push_str(&format!("cookie = \"session={session}\"\n"));
+    }
+
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| HttpError::Failed("could not pass options to curl.".into()))?
+        .write_all(config.as_bytes())
+        .map_err(|e| HttpError::Failed(e.to_string()))?;
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| HttpError::Failed(e.to_string()))?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(HttpError::Failed(error.trim().to_string()));
+    }
+
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output.rsplit_once('\n').unwrap_or(("", &output));
+
+    Ok(Response {
+        status: status
+            .trim()
+            .parse()
+            .map_err(|_| HttpError::Failed(format!("unexpected status \"{status}\".")))?,
+        body: body.to_string(),
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A local stand-in for the puzzle server, which answers every request with `respond(request)`.
+#[cfg(feature = "test_lib")]
+pub mod stub {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Start serving in the background, returning the base URL of the server.
+    pub fn serve(respond: fn(&str) -> (u16, String)) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for mut stream in listener.incoming().map_while(Result::ok) {
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                while reader.read_line(&mut request).is_ok_and(|n| n > 2) {}
+
+                let (status, body) = respond(&request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        url
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get, stub};
+
+    #[test]
+    fn sends_session_cookie() {
+        let url = stub::serve(|request| {
+            let first_line = request.lines().next().unwrap_or_default().to_string();
+            if request.contains("Cookie: session=abc123") {
+                (200, first_line)
+            } else {
+                (302, String::new())
+            }
+        });
+
+        let response = get(&format!("{url}/settings"), Some("abc123")).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "GET /settings HTTP/1.1");
+
+        let response = get(&format!("{url}/settings"), None).unwrap();
+        assert_eq!(response.status, 302);
+    }
+}
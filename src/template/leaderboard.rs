@@ -0,0 +1,592 @@
+/// Private leaderboards, fetched from the puzzle server and cached in `data/leaderboards`.
+/// The previous fetch is kept next to the cache, so that changes since then can be highlighted.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Display, Write},
+    fs, io,
+    path::PathBuf,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Day, auth,
+    http::{self, HttpError},
+};
+
+/// The server asks to not fetch a leaderboard more often than this.
+pub const FETCH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+const ANSI_GOLD: &str = "\x1b[33m";
+const ANSI_SILVER: &str = "\x1b[37m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_NEW: &str = "\x1b[1;32m";
+
+/// Puzzles unlock at midnight EST, which is UTC-5.
+const UNLOCK_OFFSET_SECONDS: i64 = -5 * 3600;
+
+#[derive(Debug)]
+pub enum FetchError {
+    Http(HttpError),
+    ExpiredSession,
+    /// The leaderboard does not exist, or the user is not a member.
+    NotFound,
+    BadStatus(u16),
+    Invalid(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Http(e) => write!(f, "{e}"),
+            FetchError::ExpiredSession => write!(
+                f,
+                "expired session. Log in again with `cargo auth login` and a new session cookie."
+            ),
+            FetchError::NotFound => write!(
+                f,
+                "the leaderboard does not exist, or you are not a member of it."
+            ),
+            FetchError::BadStatus(status) => write!(f, "the server responded with {status}."),
+            FetchError::Invalid(e) => write!(f, "the server sent an invalid leaderboard: {e}"),
+        }
+    }
+}
+
+/// Path of the cached leaderboard, e.g. `data/leaderboards/2025-123456.json`.
+#[must_use]
+pub fn get_path_for_leaderboard(year: u16, id: u64) -> PathBuf {
+    PathBuf::from("data/leaderboards").join(format!("{year}-{id}.json"))
+}
+
+/// Path of the fetch before the cached one, e.g. `data/leaderboards/2025-123456.previous.json`.
+#[must_use]
+pub fn get_path_for_previous_leaderboard(year: u16, id: u64) -> PathBuf {
+    PathBuf::from("data/leaderboards").join(format!("{year}-{id}.previous.json"))
+}
+
+/// Fetch the JSON of a leaderboard, checking that it can be parsed.
+pub fn fetch(base_url: &str, year: u16, id: u64, session: &str) -> Result<String, FetchError> {
+    let url = format!("{base_url}/{year}/leaderboard/private/view/{id}.json");
+    let response = http::get(&url, Some(session)).map_err(FetchError::Http)?;
+
+    match response.status {
+        200 => {
+            Leaderboard::parse(&response.body).map_err(FetchError::Invalid)?;
+            Ok(response.body)
+        }
+        // the server redirects both logged out users and non-members.
+        302 => match auth::validate(base_url, session) {
+            Ok(false) => Err(FetchError::ExpiredSession),
+            _ => Err(FetchError::NotFound),
+        },
+        404 => Err(FetchError::NotFound),
+        status => Err(FetchError::BadStatus(status)),
+    }
+}
+
+/// Cache a fetched leaderboard, keeping the one cached before as the previous fetch.
+pub fn store(year: u16, id: u64, json: &str) -> io::Result<()> {
+    let path = get_path_for_leaderboard(year, id);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    if path.exists() {
+        fs::rename(&path, get_path_for_previous_leaderboard(year, id))?;
+    }
+
+    fs::write(path, json)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Members can choose to be anonymous.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    /// When each part of a day was solved, as Unix timestamps.
+    pub completions: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<u64> {
+        self.completions.get(&day)?[usize::from(part - 1)]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub num_days: u8,
+    /// Members in the order of their rank.
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let json: JsonValue = json.parse().map_err(|_| "not valid JSON.".to_string())?;
+        let root = object(&json, "")?;
+
+        let year = field(root, "event", "")
+            .and_then(|value| integer(value, "event"))
+            .and_then(|year| {
+                u16::try_from(year).map_err(|_| format!("`event`: unexpected year {year}."))
+            })?;
+
+        let mut members = object(field(root, "members", "")?, "members")?
+            .iter()
+            .map(|(key, value)| parse_member(value, &format!("members.{key}")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+
+        // older events do not send the number of days, they all had 25.
+        let num_days = match root.get("num_days") {
+            Some(value) => u8::try_from(integer(value, "num_days")?)
+                .map_err(|_| "`num_days`: too many days.".to_string())?,
+            None => 25,
+        };
+
+        Ok(Leaderboard {
+            year,
+            num_days,
+            members,
+        })
+    }
+
+    fn member(&self, id: u64) -> Option<&Member> {
+        self.members.iter().find(|m| m.id == id)
+    }
+
+    /// 1-based rank of a member.
+    fn rank(&self, id: u64) -> Option<usize> {
+        self.members.iter().position(|m| m.id == id).map(|i| i + 1)
+    }
+
+    /// Unix timestamp at which the puzzle of a day unlocked.
+    fn unlock_ts(&self, day: u8) -> i64 {
+        days_from_civil(i64::from(self.year), 12, u32::from(day)) * 86400 - UNLOCK_OFFSET_SECONDS
+    }
+}
+
+fn parse_member(value: &JsonValue, path: &str) -> Result<Member, String> {
+    let member = object(value, path)?;
+    let number = |key: &str| {
+        field(member, key, path).and_then(|value| integer(value, &format!("{path}.{key}")))
+    };
+
+    let mut completions = BTreeMap::new();
+    if let Some(days) = member.get("completion_day_level") {
+        let path = format!("{path}.completion_day_level");
+        for (day, parts) in object(days, &path)? {
+            let path = format!("{path}.{day}");
+            let day: u8 = day
+                .parse()
+                .map_err(|_| format!("`{path}`: expected a day."))?;
+            let parts = object(parts, &path)?;
+
+            let mut stars = [None, None];
+            for (i, star) in stars.iter_mut().enumerate() {
+                let part = (i + 1).to_string();
+                if let Some(part_value) = parts.get(&part) {
+                    let path = format!("{path}.{part}");
+                    let ts = field(object(part_value, &path)?, "get_star_ts", &path)?;
+                    *star = Some(integer(ts, &format!("{path}.get_star_ts"))?);
+                }
+            }
+            completions.insert(day, stars);
+        }
+    }
+
+    Ok(Member {
+        id: number("id")?,
+        name: member.get("name").and_then(|n| n.get::<String>()).cloned(),
+        local_score: number("local_score")?,
+        stars: number("stars")?,
+        last_star_ts: number("last_star_ts")?,
+        completions,
+    })
+}
+
+fn object<'a>(value: &'a JsonValue, path: &str) -> Result<&'a HashMap<String, JsonValue>, String> {
+    value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| format!("`{path}`: expected an object."))
+}
+
+fn field<'a>(
+    object: &'a HashMap<String, JsonValue>,
+    key: &str,
+    path: &str,
+) -> Result<&'a JsonValue, String> {
+    object.get(key).ok_or_else(|| match path {
+        "" => format!("missing key `{key}`."),
+        path => format!("`{path}`: missing key `{key}`."),
+    })
+}
+
+/// Numbers are sent as strings in some places, e.g. the year of the event.
+fn integer(value: &JsonValue, path: &str) -> Result<u64, String> {
+    match value {
+        JsonValue::Number(n) if n.is_finite() && *n >= 0.0 => Some(*n as u64),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| format!("`{path}`: expected a non-negative integer."))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render the leaderboard as a table of ranks, scores, stars per day and the time of the last star.
+/// Changes since the `previous` fetch are highlighted.
+pub fn render(current: &Leaderboard, previous: Option<&Leaderboard>) -> String {
+    let mut out = String::new();
+
+    let days: String = (1..=current.num_days)
+        .map(|day| format!("{day:>3}"))
+        .collect();
+    let _ = writeln!(
+        out,
+        "{ANSI_BOLD}{:>5} {:>5} {:<5}{:<5}{days}  {:<15}  Name{ANSI_RESET}",
+        "Rank", "Score", "", "", "Last star (EST)"
+    );
+
+    for (i, member) in current.members.iter().enumerate() {
+        let rank = i + 1;
+        let before = previous.and_then(|p| p.member(member.id));
+
+        let (score_change, rank_change) = match (previous, before) {
+            (None, _) => (String::new(), String::new()),
+            (Some(_), None) => (String::new(), "new".into()),
+            (Some(previous), Some(before)) => {
+                let score = member.local_score.saturating_sub(before.local_score);
+                let score = if score > 0 {
+                    format!("+{score}")
+                } else {
+                    String::new()
+                };
+
+                let old_rank = previous.rank(member.id).unwrap_or(rank);
+                let rank = match old_rank.cmp(&rank) {
+                    std::cmp::Ordering::Greater => format!("▲{}", old_rank - rank),
+                    std::cmp::Ordering::Less => format!("▼{}", rank - old_rank),
+                    std::cmp::Ordering::Equal => String::new(),
+                };
+                (score, rank)
+            }
+        };
+
+        let stars: String = (1..=current.num_days)
+            .map(|day| {
+                let solved = (1..=2)
+                    .filter(|&p| member.star_ts(day, p).is_some())
+                    .count();
+                let is_new = previous.is_some()
+                    && (1..=2).any(|part| {
+                        member.star_ts(day, part).is_some()
+                            && before.and_then(|b| b.star_ts(day, part)).is_none()
+                    });
+
+                let color = match (is_new, solved) {
+                    (true, _) => ANSI_NEW,
+                    (false, 2) => ANSI_GOLD,
+                    (false, 1) => ANSI_SILVER,
+                    (false, _) => ANSI_DIM,
+                };
+                let symbol = if solved > 0 { '★' } else { '·' };
+                format!("  {color}{symbol}{ANSI_RESET}")
+            })
+            .collect();
+
+        let last_star = match member.last_star_ts {
+            0 => "-".to_string(),
+            ts => format_timestamp(ts),
+        };
+
+        let _ = writeln!(
+            out,
+            "{:>4}) {:>5} {ANSI_NEW}{score_change:<5}{rank_change:<5}{ANSI_RESET}{stars}  {last_star:<15}  {}",
+            rank,
+            member.local_score,
+            member.display_name(),
+        );
+    }
+
+    out
+}
+
+/// Render when each member solved the parts of a day, measured from the time the puzzle unlocked.
+/// Stars since the `previous` fetch are highlighted.
+pub fn render_day(current: &Leaderboard, previous: Option<&Leaderboard>, day: Day) -> String {
+    let day = day.into_inner();
+    let unlock = current.unlock_ts(day);
+    let mut out = String::new();
+
+    let mut solvers: Vec<_> = current
+        .members
+        .iter()
+        .filter(|m| m.star_ts(day, 1).is_some())
+        .collect();
+    // members that solved both parts come first.
+    solvers.sort_by_key(|m| {
+        (
+            m.star_ts(day, 2).is_none(),
+            m.star_ts(day, 2),
+            m.star_ts(day, 1),
+        )
+    });
+
+    if solvers.is_empty() {
+        let _ = writeln!(out, "Nobody has solved day {day} yet.");
+        return out;
+    }
+
+    let _ = writeln!(
+        out,
+        "{ANSI_BOLD}{:>5} {:>10} {:>10}  Name{ANSI_RESET}",
+        "", "Part 1", "Part 2"
+    );
+
+    for (i, member) in solvers.iter().enumerate() {
+        let before = previous.and_then(|p| p.member(member.id));
+        let parts: String = (1..=2)
+            .map(|part| {
+                let Some(ts) = member.star_ts(day, part) else {
+                    return format!(" {:>10}", "-");
+                };
+                let time = format_since_unlock(ts.cast_signed() - unlock);
+                let is_new =
+                    previous.is_some() && before.and_then(|b| b.star_ts(day, part)).is_none();
+
+                if is_new {
+                    format!(" {ANSI_NEW}{time:>10}{ANSI_RESET}")
+                } else {
+                    format!(" {time:>10}")
+                }
+            })
+            .collect();
+
+        let _ = writeln!(out, "{:>4}){parts}  {}", i + 1, member.display_name());
+    }
+
+    out
+}
+
+/// Format a timestamp in the time zone of the puzzle unlocks, e.g. "Dec 03 00:12:45".
+fn format_timestamp(ts: u64) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let seconds = ts.cast_signed() + UNLOCK_OFFSET_SECONDS;
+    let (_, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let time = seconds.rem_euclid(86400);
+
+    format!(
+        "{} {day:02} {:02}:{:02}:{:02}",
+        MONTHS[month as usize - 1],
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// Format the time it took to solve a part like the site does, e.g. "00:12:45" or ">24h".
+fn format_since_unlock(seconds: i64) -> String {
+    match seconds {
+        s if s >= 86400 => ">24h".into(),
+        s => {
+            let s = s.max(0);
+            format!("{:02}:{:02}:{:02}", s / 3600, s / 60 % 60, s % 60)
+        }
+    }
+}
+
+/// Days since the Unix epoch of a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date of a day since the Unix epoch, as `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        FetchError, Leaderboard, civil_from_days, days_from_civil, fetch, format_since_unlock,
+        format_timestamp, render, render_day,
+    };
+    use crate::template::{Day, http::stub};
+
+    /// Day 1 of 2025 unlocked at this timestamp.
+    const UNLOCK: u64 = 1_764_565_200;
+
+    const PREVIOUS: &str = r#"{
+        "event": "2025", "owner_id": 1, "num_days": 12,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "local_score": 6, "stars": 2, "last_star_ts": 1764565800,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1764565500, "star_index": 1},
+                                               "2": {"get_star_ts": 1764565800, "star_index": 3}}}},
+            "2": {"id": 2, "name": null, "local_score": 3, "stars": 1, "last_star_ts": 1764565600,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1764565600, "star_index": 2}}}}
+        }
+    }"#;
+
+    const CURRENT: &str = r#"{
+        "event": "2025", "owner_id": 1, "num_days": 12,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "local_score": 6, "stars": 2, "last_star_ts": 1764565800,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1764565500, "star_index": 1},
+                                               "2": {"get_star_ts": 1764565800, "star_index": 3}}}},
+            "2": {"id": 2, "name": null, "local_score": 10, "stars": 3, "last_star_ts": 1764655200,
+                "completion_day_level": {"1": {"1": {"get_star_ts": 1764565600, "star_index": 2}},
+                                         "2": {"1": {"get_star_ts": 1764655200, "star_index": 4}}}},
+            "3": {"id": 3, "name": "Carol", "local_score": 0, "stars": 0, "last_star_ts": 0,
+                "completion_day_level": {}}
+        }
+    }"#;
+
+    fn strip_ansi(text: &str) -> String {
+        let mut out = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = Leaderboard::parse(CURRENT).unwrap();
+
+        assert_eq!(leaderboard.year, 2025);
+        assert_eq!(leaderboard.num_days, 12);
+        let ids: Vec<_> = leaderboard.members.iter().map(|m| m.id).collect();
+        assert_eq!(ids, [2, 1, 3]);
+
+        let anonymous = &leaderboard.members[0];
+        assert_eq!(anonymous.display_name(), "(anonymous user #2)");
+        assert_eq!(anonymous.star_ts(2, 1), Some(1_764_655_200));
+        assert_eq!(anonymous.star_ts(1, 2), None);
+
+        assert_eq!(
+            Leaderboard::parse(r#"{"event": "2025", "members": {"1": {"id": 1}}}"#),
+            Err("`members.1`: missing key `local_score`.".into())
+        );
+    }
+
+    #[test]
+    fn highlights_changes() {
+        let current = Leaderboard::parse(CURRENT).unwrap();
+        let previous = Leaderboard::parse(PREVIOUS).unwrap();
+
+        let table = strip_ansi(&render(&current, Some(&previous)));
+        let rows: Vec<_> = table.lines().map(str::trim_end).collect();
+
+        assert!(rows[1].starts_with("   1)    10 +7   ▲1     ★  ★  ·"));
+        assert!(rows[1].ends_with("Dec 02 01:00:00  (anonymous user #2)"));
+        assert!(rows[2].starts_with("   2)     6      ▼1     ★  ·"));
+        assert!(rows[3].starts_with("   3)     0      new    ·  ·"));
+        assert!(rows[3].ends_with("-                Carol"));
+
+        // only the new star of day 2 is highlighted.
+        let table = render(&current, Some(&previous));
+        assert_eq!(table.matches("\x1b[1;32m★").count(), 1);
+        assert_eq!(render(&current, None).matches("\x1b[1;32m★").count(), 0);
+    }
+
+    #[test]
+    fn renders_star_times_of_a_day() {
+        let current = Leaderboard::parse(CURRENT).unwrap();
+        let table = strip_ansi(&render_day(&current, None, Day::new(1).unwrap()));
+        let rows: Vec<_> = table.lines().collect();
+
+        assert_eq!(rows[1], "   1)   00:05:00   00:10:00  Alice");
+        assert_eq!(rows[2], "   2)   00:06:40          -  (anonymous user #2)");
+
+        let table = render_day(&current, None, Day::new(5).unwrap());
+        assert_eq!(table, "Nobody has solved day 5 yet.\n");
+    }
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2025, 12, 1), 20423);
+        assert_eq!(civil_from_days(20423), (2025, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+        assert_eq!(format_timestamp(UNLOCK), "Dec 01 00:00:00");
+        assert_eq!(format_since_unlock(3 * 3600 + 62), "03:01:02");
+        assert_eq!(format_since_unlock(90000), ">24h");
+    }
+
+    #[test]
+    fn fetches_leaderboards() {
+        let url = stub::serve(|request| {
+            let logged_in = request.contains("session=c0ffee");
+            if request.starts_with("GET /2025/leaderboard/private/view/1.json ") && logged_in {
+                (200, CURRENT.into())
+            } else if request.starts_with("GET /2025/leaderboard/private/view/2.json ") {
+                (200, "<!DOCTYPE html>".into())
+            } else if request.starts_with("GET /settings ") && logged_in {
+                (200, "settings".into())
+            } else {
+                (302, String::new())
+            }
+        });
+
+        assert_eq!(fetch(&url, 2025, 1, "c0ffee").unwrap(), CURRENT);
+        assert!(matches!(
+            fetch(&url, 2025, 1, "deadbeef"),
+            Err(FetchError::ExpiredSession)
+        ));
+        assert!(matches!(
+            fetch(&url, 2025, 3, "c0ffee"),
+            Err(FetchError::NotFound)
+        ));
+        assert!(matches!(
+            fetch(&url, 2025, 2, "c0ffee"),
+            Err(FetchError::Invalid(_))
+        ));
+    }
+}
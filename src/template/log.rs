@@ -0,0 +1,119 @@
+/// Logging for solutions, enabled with `solve N -v` (or `-vv`, `-vvv` for more detail).
+/// Messages are written to stderr, so that they never mix with the answers other commands parse,
+/// and are disabled entirely while benchmarking under `--time`.
+use std::{env, fmt::Arguments, sync::OnceLock};
+
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+/// The verbosity a solution is run with, e.g. 2 for `-vv`. Always 0 when benchmarking under `--time`.
+pub fn verbosity() -> u8 {
+    static VERBOSITY: OnceLock<u8> = OnceLock::new();
+    *VERBOSITY.get_or_init(|| parse_verbosity(env::args()))
+}
+
+fn parse_verbosity(args: impl IntoIterator<Item = String>) -> u8 {
+    let mut verbosity: u8 = 0;
+
+    for arg in args {
+        if arg == "--time" {
+            return 0;
+        }
+        if let Some(flags) = arg.strip_prefix('-')
+            && !flags.is_empty()
+            && flags.chars().all(|c| c == 'v')
+        {
+            let count = u8::try_from(flags.len()).unwrap_or(u8::MAX);
+            verbosity = verbosity.saturating_add(count);
+        }
+    }
+
+    verbosity
+}
+
+pub fn enabled(level: Level) -> bool {
+    verbosity() >= level as u8
+}
+
+/// Write a message to stderr. Use the [`info!`](crate::info), [`debug!`](crate::debug)
+/// and [`trace!`](crate::trace) macros instead, which skip formatting when the level is disabled.
+pub fn write(level: Level, message: Arguments) {
+    eprintln!("{ANSI_ITALIC}[{}]{ANSI_RESET} {message}", level.name());
+}
+
+/// Log a message at a [`Level`](crate::template::log::Level), if the solution is run verbosely enough.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::template::log::enabled($level) {
+            $crate::template::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+/// Log a message with `solve N -v`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Info, $($arg)+)
+    };
+}
+
+/// Log a message with `solve N -vv`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Debug, $($arg)+)
+    };
+}
+
+/// Log a message with `solve N -vvv`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::template::log::Level::Trace, $($arg)+)
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_verbosity;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_verbosity() {
+        assert_eq!(parse_verbosity(args(&["target/release/01"])), 0);
+        assert_eq!(parse_verbosity(args(&["01", "-v"])), 1);
+        assert_eq!(
+            parse_verbosity(args(&["01", "-vv", "-v", "--submit", "1"])),
+            3
+        );
+        assert_eq!(parse_verbosity(args(&["01", "-x", "-"])), 0);
+    }
+
+    #[test]
+    fn is_disabled_when_benchmarking() {
+        assert_eq!(parse_verbosity(args(&["01", "-vvv", "--time"])), 0);
+    }
+}
@@ -0,0 +1,377 @@
+/// Module that renders the puzzle markdown written by aoc-cli to the terminal.
+/// Only the subset of markdown that aoc-cli produces is supported.
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_CYAN: &str = "\x1b[36m";
+
+const MAX_WIDTH: usize = 100;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Style {
+    code: bool,
+    emphasis: bool,
+    strong: bool,
+    link: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::new();
+        if self.emphasis || self.strong {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.emphasis && self.code {
+            s.push_str(ANSI_YELLOW);
+        } else if self.code {
+            s.push_str(ANSI_CYAN);
+        } else if self.emphasis {
+            s.push_str(ANSI_ITALIC);
+        }
+        if self.link {
+            s.push_str(ANSI_UNDERLINE);
+        }
+        s
+    }
+
+    fn is_plain(self) -> bool {
+        self == Style::default()
+    }
+}
+
+/// A word of a paragraph, tracking its visible width separately from its escape codes.
+#[derive(Default)]
+struct Word {
+    rendered: String,
+    width: usize,
+    style: Style,
+}
+
+impl Word {
+    fn push(&mut self, c: char, style: Style) {
+        if style != self.style {
+            if !self.style.is_plain() {
+                self.rendered.push_str(ANSI_RESET);
+            }
+            self.rendered.push_str(&style.ansi());
+            self.style = style;
+        }
+        self.rendered.push(c);
+        self.width += 1;
+    }
+
+    fn finish(mut self) -> Self {
+        if !self.style.is_plain() {
+            self.rendered.push_str(ANSI_RESET);
+        }
+        self
+    }
+}
+
+/// Whether the text starts with a link, i.e. `[text](url)`. Other brackets are plain text.
+fn is_link(chars: &[char]) -> bool {
+    chars
+        .iter()
+        .position(|c| *c == ']')
+        .is_some_and(|end| chars.get(end + 1) == Some(&'('))
+}
+
+/// Split inline markdown into styled words.
+fn parse_inline(text: &str) -> Vec<Word> {
+    let mut words = vec![];
+    let mut word = Word::default();
+    let mut style = Style::default();
+    let mut link_url = false;
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            '\\' if next.is_some_and(|n| n.is_ascii_punctuation()) => {
+                word.push(next.unwrap(), style);
+                i += 1;
+            }
+            '`' => style.code = !style.code,
+            '*' if next == Some('*') && !style.code => {
+                style.strong = !style.strong;
+                i += 1;
+            }
+            // in code, only emphasis of the whole span counts, e.g. `*13*` for a highlighted answer.
+            '*' if !style.code
+                || i.checked_sub(1).map(|p| chars[p]) == Some('`')
+                || next == Some('`') =>
+            {
+                style.emphasis = !style.emphasis;
+            }
+            '[' if !style.code && is_link(&chars[i..]) => style.link = true,
+            ']' if style.link => {
+                // skip the `(` of the url.
+                style.link = false;
+                link_url = true;
+                i += 1;
+            }
+            ')' if link_url => link_url = false,
+            _ if link_url => {}
+            c if c.is_whitespace() && !style.code => {
+                if word.width > 0 {
+                    words.push(std::mem::take(&mut word).finish());
+                }
+            }
+            c => word.push(c, style),
+        }
+
+        i += 1;
+    }
+
+    if word.width > 0 {
+        words.push(word.finish());
+    }
+
+    words
+}
+
+/// Wrap words to lines of at most `width` visible characters.
+fn wrap(words: &[Word], width: usize, indent: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::from(indent);
+    let mut line_width = indent.len();
+
+    for word in words {
+        if line_width > indent.len() && line_width + 1 + word.width > width {
+            lines.push(line);
+            line = String::from(indent);
+            line_width = indent.len();
+        }
+        if line_width > indent.len() {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(&word.rendered);
+        line_width += word.width;
+    }
+
+    if line_width > indent.len() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn is_heading(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start_matches(['#', ' ']).trim_start_matches('\\');
+    let is_heading = trimmed.starts_with("--- ") && trimmed.ends_with(" ---");
+    is_heading.then_some(trimmed)
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && line.chars().all(|c| c == '-' || c == '=')
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    trimmed
+        .strip_prefix("* ")
+        .or_else(|| trimmed.strip_prefix("- "))
+}
+
+/// Render puzzle markdown to lines of ANSI-styled text, wrapped at `width` columns.
+pub fn render(markdown: &str, width: usize) -> Vec<String> {
+    let mut output: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut in_code_block = false;
+
+    let flush = |paragraph: &mut Vec<&str>, output: &mut Vec<String>| {
+        if paragraph.is_empty() {
+            return;
+        }
+        match list_item(paragraph[0]) {
+            Some(_) => {
+                // continuation lines belong to the preceding item.
+                let mut items: Vec<String> = vec![];
+                for line in paragraph.iter() {
+                    match (list_item(line), items.last_mut()) {
+                        (None, Some(item)) => {
+                            item.push(' ');
+                            item.push_str(line.trim());
+                        }
+                        (item, _) => items.push(item.unwrap_or(line).to_string()),
+                    }
+                }
+                for item in items {
+                    let mut lines = wrap(&parse_inline(&item), width, "    ");
+                    if let Some(first) = lines.first_mut() {
+                        first.replace_range(0..4, "  • ");
+                    }
+                    output.extend(lines);
+                }
+            }
+            None => output.extend(wrap(&parse_inline(&paragraph.join(" ")), width, "")),
+        }
+        output.push(String::new());
+        paragraph.clear();
+    };
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            flush(&mut paragraph, &mut output);
+            in_code_block = !in_code_block;
+            if !in_code_block {
+                output.push(String::new());
+            }
+            continue;
+        }
+
+        if in_code_block {
+            output.push(format!("    {ANSI_DIM}{line}{ANSI_RESET}"));
+        } else if let Some(heading) = is_heading(line) {
+            flush(&mut paragraph, &mut output);
+            output.push(format!("{ANSI_BOLD}{ANSI_GREEN}{heading}{ANSI_RESET}"));
+            output.push(String::new());
+        } else if is_rule(line) || line.trim().is_empty() {
+            flush(&mut paragraph, &mut output);
+        } else {
+            if list_item(line).is_some()
+                && paragraph.first().is_some_and(|l| list_item(l).is_none())
+            {
+                flush(&mut paragraph, &mut output);
+            }
+            paragraph.push(line);
+        }
+    }
+
+    flush(&mut paragraph, &mut output);
+
+    while output.last().is_some_and(String::is_empty) {
+        output.pop();
+    }
+
+    output
+}
+
+/// Query the terminal size as `(rows, columns)`.
+pub fn terminal_size() -> Option<(usize, usize)> {
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    let size = String::from_utf8(output.stdout).ok()?;
+    let (rows, cols) = size.trim().split_once(' ')?;
+    Some((rows.parse().ok()?, cols.parse().ok()?))
+}
+
+/// Print rendered lines, using a pager if they do not fit on the screen.
+pub fn print_paged(lines: &[String]) {
+    let text = lines.join("\n");
+    let is_terminal = io::stdout().is_terminal();
+    let rows = terminal_size().map_or(usize::MAX, |(rows, _)| rows);
+
+    if is_terminal && lines.len() >= rows {
+        let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+        let mut parts = pager.split_whitespace();
+        if let Some(cmd) = parts.next()
+            && let Ok(mut child) = Command::new(cmd).args(parts).stdin(Stdio::piped()).spawn()
+        {
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = writeln!(stdin, "{text}");
+            }
+            let _ = child.wait();
+            return;
+        }
+    }
+
+    println!("{text}");
+}
+
+/// Width to render at, based on the terminal size.
+pub fn get_width() -> usize {
+    terminal_size().map_or(80, |(_, cols)| cols).min(MAX_WIDTH)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_inline, render};
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut in_escape = false;
+        for c in s.chars() {
+            match c {
+                '\x1b' => in_escape = true,
+                'm' if in_escape => in_escape = false,
+                _ if in_escape => {}
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn parses_inline_styles() {
+        let words: Vec<String> = parse_inline("a `*13*`. *b* [c](http://x) \\--- d")
+            .iter()
+            .map(|w| strip_ansi(&w.rendered))
+            .collect();
+        assert_eq!(words, vec!["a", "13.", "b", "c", "---", "d"]);
+    }
+
+    #[test]
+    fn keeps_asterisks_in_code() {
+        let words = parse_inline("`a*b` c `*13*`");
+        assert_eq!(strip_ansi(&words[0].rendered), "a*b");
+        assert!(!words[1].rendered.contains("\x1b[3m"));
+        assert!(words[2].rendered.contains("\x1b[33m13"));
+    }
+
+    #[test]
+    fn keeps_brackets_that_are_not_links() {
+        let words: Vec<String> = parse_inline("[1,2] and [a] [b](c)")
+            .iter()
+            .map(|w| strip_ansi(&w.rendered))
+            .collect();
+        assert_eq!(words, vec!["[1,2]", "and", "[a]", "b"]);
+    }
+
+    #[test]
+    fn wraps_paragraphs() {
+        let markdown = "one two three\nfour five six seven";
+        let lines: Vec<String> = render(markdown, 10).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(lines, vec!["one two", "three four", "five six", "seven"]);
+    }
+
+    #[test]
+    fn renders_blocks() {
+        let markdown =
+            "\\--- Day 1: Test ---\n----------\n\nFor example:\n\n```\n  L68\n```\n\n* a\n* b";
+        let lines: Vec<String> = render(markdown, 80).iter().map(|l| strip_ansi(l)).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "--- Day 1: Test ---",
+                "",
+                "For example:",
+                "",
+                "      L68",
+                "",
+                "  • a",
+                "  • b",
+            ]
+        );
+    }
+}
@@ -0,0 +1,340 @@
+/// Delta debugging of puzzle inputs: reduces an input on which a part fails to a small reproducer.
+/// The solution bin runs this in-process when started with `--minimize <part>`, see the `minimize` command.
+use std::{fmt::Display, fs, ops::Range, process};
+
+use crate::template::{
+    Day,
+    params::{RawParams, Solver},
+    runner::{Panic, catch_panic, variant_name},
+};
+
+/// Why a part fails on an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Panic(Panic),
+    /// The answers of all variants, which are not all the same.
+    Disagreement(Vec<(String, Option<String>)>),
+}
+
+impl Failure {
+    /// Whether a failure on a reduced input is the one being minimized.
+    /// Panics have to happen at the same location, so that e.g. a parser panicking on a cut-off line does not count.
+    fn reproduces(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (Failure::Panic(a), Failure::Panic(b)) => a.location == b.location,
+            (Failure::Disagreement(_), Failure::Disagreement(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panic(panic) => write!(f, "{panic}"),
+            Failure::Disagreement(results) => {
+                let results: Vec<String> = results
+                    .iter()
+                    .map(|(name, result)| format!("{name}: {}", result.as_deref().unwrap_or("✖")))
+                    .collect();
+                write!(f, "variants disagree ({})", results.join(", "))
+            }
+        }
+    }
+}
+
+/// Run one variant of a part, catching panics.
+pub fn try_variant<M, S: Solver<M>>(
+    solver: S,
+    input: &str,
+    params: &RawParams,
+) -> Result<Option<String>, Panic> {
+    let params = S::parse_params(params).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    catch_panic(|| solver.solve(input, &params).map(|r| r.to_string()))
+}
+
+/// The failure of a part, given the result of each of its variants, named after their functions.
+pub fn check(names: &[&str], results: Vec<Result<Option<String>, Panic>>) -> Option<Failure> {
+    let mut answers = vec![];
+
+    for (name, result) in names.iter().zip(results) {
+        match result {
+            Ok(answer) => answers.push((variant_name(name).to_string(), answer)),
+            Err(panic) => return Some(Failure::Panic(panic)),
+        }
+    }
+
+    let disagree = answers.windows(2).any(|pair| pair[0].1 != pair[1].1);
+    disagree.then_some(Failure::Disagreement(answers))
+}
+
+#[must_use]
+pub fn get_path_for_reproducer(day: Day) -> String {
+    format!("data/examples/{day}-min.txt")
+}
+
+/// Minimize the input of a part and write the smallest reproducer to the examples, exiting if the part does not fail.
+pub fn run(day: Day, part: u8, input: &str, failure: impl Fn(&str) -> Option<Failure>) {
+    let Some(expected) = failure(input) else {
+        eprintln!("Part {part} does not fail on the input, so there is nothing to minimize.");
+        process::exit(1);
+    };
+
+    println!("Part {part}: {expected}");
+    println!("Minimizing {} lines...", input.lines().count());
+
+    let reproducer = minimize(input, |candidate| {
+        failure(candidate).is_some_and(|f| expected.reproduces(&f))
+    });
+
+    let path = get_path_for_reproducer(day);
+    if let Err(e) = fs::write(&path, &reproducer) {
+        eprintln!("Failed to write reproducer to \"{path}\": {e}");
+        process::exit(1);
+    }
+
+    if let Some(failure) = failure(&reproducer) {
+        println!("Part {part}: {failure}");
+    }
+    println!(
+        "Wrote the smallest reproducer ({} lines, {} bytes) to \"{path}\".",
+        reproducer.lines().count(),
+        reproducer.len()
+    );
+}
+
+/// Reduce `input` while it `fails`: drop lines, crop grids and shrink numbers, until none of them reduces it further.
+pub fn minimize(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let trailing_newline = if input.ends_with('\n') { "\n" } else { "" };
+    let mut input = input.to_string();
+
+    loop {
+        let before = input.clone();
+
+        input = drop_lines(&input, trailing_newline, &mut fails);
+        input = crop_columns(&input, trailing_newline, &mut fails);
+        input = shrink_numbers(&input, &mut fails);
+
+        if input == before {
+            return input;
+        }
+    }
+}
+
+fn join_lines(lines: &[&str], trailing_newline: &str) -> String {
+    if lines.is_empty() {
+        return String::new();
+    }
+    lines.join("\n") + trailing_newline
+}
+
+fn drop_lines(input: &str, trailing_newline: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+    let lines = ddmin(lines, |lines| fails(&join_lines(lines, trailing_newline)));
+    join_lines(&lines, trailing_newline)
+}
+
+/// Remove columns of a grid, i.e. an input of at least two lines of equal width.
+fn crop_columns(
+    input: &str,
+    trailing_newline: &str,
+    fails: &mut impl FnMut(&str) -> bool,
+) -> String {
+    let rows: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let width = rows.first().map_or(0, Vec::len);
+
+    if rows.len() < 2 || width < 2 || rows.iter().any(|row| row.len() != width) {
+        return input.to_string();
+    }
+
+    let crop = |columns: &[usize]| {
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| columns.iter().map(|c| row[*c]).collect())
+            .collect();
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        join_lines(&lines, trailing_newline)
+    };
+
+    let columns = ddmin((0..width).collect(), |columns| fails(&crop(columns)));
+    crop(&columns)
+}
+
+/// Byte ranges of all unsigned integers in the input.
+fn number_ranges(input: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+
+    for (i, c) in input.char_indices() {
+        if !c.is_ascii_digit() {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(range) if range.end == i => range.end = i + 1,
+            _ => ranges.push(i..i + 1),
+        }
+    }
+
+    ranges
+}
+
+/// Replace every number with the smallest value that still fails, found with a binary search.
+/// Assumes that values fail from some threshold on, otherwise the search finds some smaller value that fails.
+fn shrink_numbers(input: &str, fails: &mut impl FnMut(&str) -> bool) -> String {
+    let mut input = input.to_string();
+    let mut index = 0;
+
+    while let Some(range) = number_ranges(&input).get(index).cloned() {
+        index += 1;
+        let Ok(value) = input[range.clone()].parse::<u64>() else {
+            continue;
+        };
+
+        let with = |candidate: u64| {
+            let mut candidate_input = input.clone();
+            candidate_input.replace_range(range.clone(), &candidate.to_string());
+            candidate_input
+        };
+
+        // `high` always fails, values below `low` were ruled out.
+        let (mut low, mut high) = (0, value);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if fails(&with(mid)) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        if high < value {
+            input = with(high);
+        }
+    }
+
+    input
+}
+
+/// Delta debugging: remove chunks of `items` while the remainder still `fails`, refining the chunks when none can be removed.
+fn ddmin<T: Clone>(mut items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+
+    while items.len() >= 2 {
+        let chunk_size = items.len().div_ceil(chunks);
+
+        let reduced = (0..items.len()).step_by(chunk_size).find_map(|start| {
+            let end = (start + chunk_size).min(items.len());
+            let complement: Vec<T> = items[..start]
+                .iter()
+                .chain(&items[end..])
+                .cloned()
+                .collect();
+            fails(&complement).then_some(complement)
+        });
+
+        match reduced {
+            Some(reduced) => {
+                items = reduced;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks >= items.len() => break,
+            None => chunks = (chunks * 2).min(items.len()),
+        }
+    }
+
+    items
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Failure, check, ddmin, minimize, number_ranges, shrink_numbers};
+    use crate::template::runner::Panic;
+
+    #[test]
+    fn finds_minimal_subset() {
+        let items: Vec<u32> = (0..100).collect();
+        let result = ddmin(items, |items| items.contains(&17) && items.contains(&64));
+        assert_eq!(result, [17, 64]);
+    }
+
+    #[test]
+    fn finds_number_ranges() {
+        assert_eq!(number_ranges("12,3\nx45"), [0..2, 3..4, 6..8]);
+    }
+
+    #[test]
+    fn minimizes_lines_and_numbers() {
+        let input = "1 2\n3 40\n5 600\n7 8\n";
+        // fails whenever a line has a number of at least 30.
+        let result = minimize(input, |input| {
+            input
+                .split_whitespace()
+                .any(|n| n.parse::<u32>().is_ok_and(|n| n >= 30))
+        });
+        assert_eq!(result, "0 30\n");
+    }
+
+    #[test]
+    fn shrinks_large_numbers_quickly() {
+        let mut runs = 0;
+        let result = shrink_numbers("99999 12345", &mut |input| {
+            runs += 1;
+            input
+                .split_whitespace()
+                .any(|n| n.parse::<u32>().is_ok_and(|n| n >= 30))
+        });
+        assert_eq!(result, "0 30");
+        assert!(runs <= 40, "took {runs} runs");
+    }
+
+    #[test]
+    fn crops_grids() {
+        let input = "....\n.#..\n....\n";
+        let result = minimize(input, |input| {
+            input.lines().count() >= 2 && input.contains('#')
+        });
+        assert_eq!(result, ".\n#\n");
+    }
+
+    #[test]
+    fn checks_variants() {
+        let ok = |answer: &str| Ok(Some(answer.to_string()));
+        let names = ["part_two_lp", "part_two_bnb"];
+
+        assert_eq!(check(&names, vec![ok("1"), ok("1")]), None);
+        assert_eq!(
+            check(&names, vec![ok("1"), ok("2")]),
+            Some(Failure::Disagreement(vec![
+                ("lp".into(), Some("1".into())),
+                ("bnb".into(), Some("2".into()))
+            ]))
+        );
+
+        let panic = Panic {
+            message: "oops".into(),
+            location: Some("src/bin/10.rs:1:1".into()),
+        };
+        assert_eq!(
+            check(&names, vec![ok("1"), Err(panic.clone())]),
+            Some(Failure::Panic(panic))
+        );
+    }
+
+    #[test]
+    fn reproduces_panics_at_the_same_location() {
+        let panic = |location: &str| {
+            Failure::Panic(Panic {
+                message: "oops".into(),
+                location: Some(location.into()),
+            })
+        };
+        assert!(panic("a.rs:1:1").reproduces(&panic("a.rs:1:1")));
+        assert!(!panic("a.rs:1:1").reproduces(&panic("a.rs:2:1")));
+        assert!(!panic("a.rs:1:1").reproduces(&Failure::Disagreement(vec![])));
+    }
+}
@@ -1,14 +1,37 @@
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod auth;
 pub mod commands;
+pub mod examples;
+pub mod http;
+pub mod leaderboard;
+pub mod log;
+pub mod minimize;
+pub mod ocr;
+pub mod params;
+pub mod puzzle;
+pub mod rng;
 pub mod runner;
+pub mod viz;
 
 pub use day::*;
 
+mod allocations;
+mod baseline;
+mod benchmark_chart;
+mod dashboard;
 mod day;
+mod determinism;
+mod markdown;
+mod profiles;
 mod readme_benchmarks;
+mod readme_stars;
+mod report;
 mod run_multi;
+mod scale;
+mod search;
+mod submissions;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
@@ -39,19 +62,43 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Parts either take the input only, or the input and [puzzle parameters](crate::template::params).
+///
+/// Instead, parts can also list any number of variants, which are all run, benchmarked and checked to agree:
+/// ```ignore
+/// advent_of_code::solution!(10, {
+///     1: [part_one],
+///     2: [part_two_lp, part_two_bnb],
+/// });
+/// ```
+///
+/// With `--minimize <part>`, the input is reduced to a small reproducer of a panic or disagreement of variants instead.
+///
+/// A day can also register an input generator `fn(size: usize, seed: u64) -> String`,
+/// which the `scale` command uses to benchmark the parts over increasing input sizes.
+/// Generators of days with parameters return `(String, RawParams)` instead, so that the parameters fit the size:
+/// ```ignore
+/// advent_of_code::solution!(8, generator = generate);
+/// ```
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [], [1, part_one] [2, part_two]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [], [1, part_one]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [], [2, part_two]);
+    };
+    ($day:expr, generator = $gen:ident) => {
+        $crate::solution!(@impl $day, [$gen], [1, part_one] [2, part_two]);
+    };
+    ($day:expr, { $( $part:literal : [ $( $func:ident ),+ $(,)? ] ),+ $(,)? } $(, generator = $gen:ident)? $(,)?) => {
+        $crate::solution!(@impl $day, [$( $gen )?], $( [$part, $( $func )+] )+);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$( $gen:ident )?], $( [$part:literal, $( $func:ident )+] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +108,55 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
+
+            fn run_parts(input: &str, params: &$crate::template::params::RawParams, size: Option<usize>) {
+                $(
+                    let mut variants = Variants::new(DAY, $part, vec![$( stringify!($func) ),+]).with_size(size);
+                    $( variants.run($func, input, params); )+
+                    variants.finish();
+                )*
+            }
+
+            fn failure(
+                part: u8,
+                input: &str,
+                params: &$crate::template::params::RawParams,
+            ) -> Option<$crate::template::minimize::Failure> {
+                use $crate::template::minimize::{check, try_variant};
+                $(
+                    if part == $part {
+                        return check(
+                            &[$( stringify!($func) ),+],
+                            vec![$( try_variant($func, input, params) ),+],
+                        );
+                    }
+                )*
+                None
+            }
+
+            let params = read_params(DAY);
+
+            if let Some(part) = minimize_args() {
+                let input = $crate::template::read_file("inputs", DAY);
+                $crate::template::minimize::run(DAY, part, &input, |input| failure(part, input, &params));
+                return;
+            }
+
+            if let Some(scale) = scale_args() {
+                $(
+                    for size in scale.sizes {
+                        use $crate::template::params::Generated;
+                        let (input, generated) = $gen(size, scale.seed).into_input();
+                        run_parts(&input, generated.as_ref().unwrap_or(&params), Some(size));
+                    }
+                    return;
+                )?
+                #[allow(unreachable_code)]
+                missing_generator(DAY);
+            }
+
             let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run_parts(&input, &params, None);
         }
     };
 }
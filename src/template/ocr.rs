@@ -0,0 +1,149 @@
+/// Recognition of the block letters some puzzles draw as their answer, in the 4x6 and 6x10 sizes of the font.
+/// Multi-line answers are recognized by the runner, so that their letters can be submitted.
+use std::iter;
+
+/// A size of the font: the known `letters`, rendered `pitch` columns apart, which includes the space between them.
+struct Font {
+    letters: &'static str,
+    pitch: usize,
+    rendered: &'static str,
+}
+
+const SMALL: Font = Font {
+    letters: "ABCEFGHIJKLOPRSUYZ",
+    pitch: 5,
+    rendered: "\
+.##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#...#####.
+#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#...#.
+#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#...#..
+####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#...#...
+#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#....
+#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#..####.",
+};
+
+const LARGE: Font = Font {
+    letters: "ABCEFGHJKLNPRXZ",
+    pitch: 8,
+    rendered: "\
+..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######..
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#..
+#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#..
+#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#...
+#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#....
+######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#.....
+#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#......
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.......
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.......
+#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######..",
+};
+
+/// The lit cells of a rendered grid: cells other than `.` and spaces.
+fn parse_cells(text: &str) -> Vec<Vec<bool>> {
+    text.lines()
+        .map(|line| line.chars().map(|c| !matches!(c, '.' | ' ')).collect())
+        .collect()
+}
+
+/// Recognize the letters of a rendered answer, e.g. `.##.\n#..#\n...`. Cells other than `.` and spaces are lit.
+pub fn recognize(text: &str) -> Option<String> {
+    recognize_grid(&parse_cells(text))
+}
+
+/// Recognize the letters of a grid of lit cells. Empty rows above and below the letters are ignored.
+/// Returns `None` unless the grid is as high as a size of the font, and every letter is known.
+pub fn recognize_grid<R: AsRef<[bool]>>(rows: &[R]) -> Option<String> {
+    let is_lit = |row: &R| row.as_ref().contains(&true);
+    let first = rows.iter().position(is_lit)?;
+    let last = rows.iter().rposition(is_lit)?;
+    let rows = &rows[first..=last];
+
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.rendered.lines().count() == rows.len())?;
+    let glyphs = parse_cells(font.rendered);
+
+    let width = rows
+        .iter()
+        .filter_map(|row| row.as_ref().iter().rposition(|lit| *lit))
+        .max()?
+        + 1;
+
+    (0..width.div_ceil(font.pitch))
+        .map(|index| {
+            let cells = letter_cells(rows, index, font.pitch);
+            font.letters.chars().enumerate().find_map(|(i, letter)| {
+                (letter_cells(&glyphs, i, font.pitch) == cells).then_some(letter)
+            })
+        })
+        .collect()
+}
+
+/// The cells of the letter at `index` of a grid, including the space after it.
+fn letter_cells<R: AsRef<[bool]>>(rows: &[R], index: usize, pitch: usize) -> Vec<bool> {
+    rows.iter()
+        .flat_map(|row| {
+            let row = row.as_ref().get(index * pitch..).unwrap_or_default();
+            row.iter().copied().chain(iter::repeat(false)).take(pitch)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{LARGE, SMALL, recognize, recognize_grid};
+
+    #[test]
+    fn recognizes_every_letter() {
+        for font in [SMALL, LARGE] {
+            assert_eq!(recognize(font.rendered).as_deref(), Some(font.letters));
+        }
+    }
+
+    #[test]
+    fn recognizes_small_letters() {
+        let text = "\
+#..#..###.####.#...#
+#..#...#..#....#...#
+####...#..###...#.#.
+#..#...#..#......#..
+#..#...#..#......#..
+#..#..###.####...#..";
+        assert_eq!(recognize(text).as_deref(), Some("HIEY"));
+    }
+
+    #[test]
+    fn recognizes_large_letters() {
+        let text = "
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..#.....
+
+";
+        assert_eq!(recognize(text).as_deref(), Some("XF"));
+    }
+
+    #[test]
+    fn recognizes_grids_of_bools() {
+        let rows: Vec<Vec<bool>> = [".##.", "#..#", "#...", "#...", "#..#", ".##."]
+            .iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(recognize_grid(&rows).as_deref(), Some("C"));
+    }
+
+    #[test]
+    fn rejects_unknown_shapes() {
+        assert_eq!(recognize("##\n##"), None);
+        assert_eq!(recognize(""), None);
+        assert_eq!(recognize("#.#.\n.#..\n#.#.\n.#..\n#.#.\n.#.."), None);
+    }
+}
@@ -0,0 +1,216 @@
+/// Module for puzzle parameters, i.e. values that differ between the example and the real input.
+///
+/// Parameters of the real input are declared in `data/inputs/NN.json`, e.g. `{ "connections": 1000 }`,
+/// parameters of examples in the `params` of their entry in the example manifest.
+/// A part receives them as a typed struct declared with [`params!`](crate::params):
+/// ```ignore
+/// advent_of_code::params! {
+///     pub struct Params {
+///         pub connections: usize,
+///     }
+/// }
+///
+/// pub fn part_one(input: &str, params: &Params) -> Option<u64> { ... }
+/// ```
+use std::{collections::HashMap, env, fmt::Display, fs, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+/// Raw parameters as declared in the JSON files.
+pub type RawParams = HashMap<String, String>;
+
+/// A set of typed parameters that can be parsed from the declared values.
+pub trait PuzzleParams: Sized {
+    fn from_params(params: &RawParams) -> Result<Self, String>;
+}
+
+/// Parse a single parameter, used by [`params!`](crate::params).
+pub fn parse_param<T: FromStr>(params: &RawParams, name: &str) -> Result<T, String> {
+    let value = params
+        .get(name)
+        .ok_or_else(|| format!("missing parameter `{name}`."))?;
+    value
+        .parse()
+        .map_err(|_| format!("could not parse parameter `{name}` from \"{value}\"."))
+}
+
+/// A solution part, either `fn(&str) -> Option<T>` or `fn(&str, &P) -> Option<T>` with `P: PuzzleParams`.
+/// The `Marker` type only serves to tell the two implementations apart.
+pub trait Solver<Marker> {
+    type Params;
+    type Output: Display;
+
+    fn parse_params(params: &RawParams) -> Result<Self::Params, String>;
+    fn solve(&self, input: &str, params: &Self::Params) -> Option<Self::Output>;
+}
+
+impl<F, T> Solver<fn(&str) -> T> for F
+where
+    F: Fn(&str) -> Option<T>,
+    T: Display,
+{
+    type Params = ();
+    type Output = T;
+
+    fn parse_params(_: &RawParams) -> Result<Self::Params, String> {
+        Ok(())
+    }
+
+    fn solve(&self, input: &str, _: &Self::Params) -> Option<T> {
+        self(input)
+    }
+}
+
+impl<F, T, P> Solver<fn(&str, &P) -> T> for F
+where
+    F: Fn(&str, &P) -> Option<T>,
+    T: Display,
+    P: PuzzleParams,
+{
+    type Params = P;
+    type Output = T;
+
+    fn parse_params(params: &RawParams) -> Result<Self::Params, String> {
+        P::from_params(params)
+    }
+
+    fn solve(&self, input: &str, params: &Self::Params) -> Option<T> {
+        self(input, params)
+    }
+}
+
+/// What an input generator returns: the input only, or the input and parameters that fit its size.
+pub trait Generated {
+    fn into_input(self) -> (String, Option<RawParams>);
+}
+
+impl Generated for String {
+    fn into_input(self) -> (String, Option<RawParams>) {
+        (self, None)
+    }
+}
+
+impl Generated for (String, RawParams) {
+    fn into_input(self) -> (String, Option<RawParams>) {
+        (self.0, Some(self.1))
+    }
+}
+
+#[must_use]
+pub fn get_path_for_input_params(day: Day) -> String {
+    format!("data/inputs/{day}.json")
+}
+
+/// Convert a JSON object of parameters to raw parameters.
+pub fn from_json(value: &JsonValue) -> Result<RawParams, String> {
+    value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected parameters to be an object.")?
+        .iter()
+        .map(|(k, v)| match v {
+            JsonValue::String(s) => Ok((k.clone(), s.clone())),
+            JsonValue::Number(n) if n.fract() == 0.0 => Ok((k.clone(), format!("{n:.0}"))),
+            JsonValue::Number(n) => Ok((k.clone(), n.to_string())),
+            JsonValue::Boolean(b) => Ok((k.clone(), b.to_string())),
+            _ => Err(format!(
+                "expected parameter `{k}` to be a number, string or boolean."
+            )),
+        })
+        .collect()
+}
+
+/// Read the parameters of the real input. If not present, returns no parameters.
+pub fn read_input_params(day: Day) -> Result<RawParams, String> {
+    let cwd = env::current_dir().map_err(|e| e.to_string())?;
+    let path = get_path_for_input_params(day);
+    match fs::read_to_string(cwd.join(&path)) {
+        Ok(s) => JsonValue::from_str(&s)
+            .map_err(|_| format!("{path} is not a valid JSON file."))
+            .and_then(|json| from_json(&json)),
+        Err(_) => Ok(RawParams::new()),
+    }
+}
+
+/// Declares a struct of puzzle parameters and implements [`PuzzleParams`] for it.
+/// Every field is parsed with [`FromStr`] from the value of the same name.
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field_vis:vis $field:ident: $ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        $vis struct $name {
+            $( $(#[$field_meta])* $field_vis $field: $ty ),*
+        }
+
+        impl $crate::template::params::PuzzleParams for $name {
+            fn from_params(
+                params: &$crate::template::params::RawParams,
+            ) -> Result<Self, String> {
+                Ok(Self {
+                    $( $field: $crate::template::params::parse_param(params, stringify!($field))? ),*
+                })
+            }
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+    use tinyjson::JsonValue;
+
+    use super::{PuzzleParams, RawParams, Solver, from_json};
+
+    crate::params! {
+        struct Params {
+            connections: usize,
+            name: String,
+        }
+    }
+
+    fn get_raw_params() -> RawParams {
+        let json = JsonValue::from_str(r#"{ "connections": 10, "name": "example" }"#).unwrap();
+        from_json(&json).unwrap()
+    }
+
+    fn part_without_params(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
+
+    fn part_with_params(input: &str, params: &Params) -> Option<usize> {
+        Some(input.len() * params.connections)
+    }
+
+    fn solve<M, S: Solver<M>>(solver: S, params: &RawParams) -> Option<String> {
+        let params = S::parse_params(params).unwrap();
+        solver.solve("abc", &params).map(|r| r.to_string())
+    }
+
+    #[test]
+    fn parses_params() {
+        let params = Params::from_params(&get_raw_params()).unwrap();
+        assert_eq!(params.connections, 10);
+        assert_eq!(params.name, "example");
+    }
+
+    #[test]
+    fn reports_missing_params() {
+        let err = Params::from_params(&RawParams::new()).unwrap_err();
+        assert_eq!(err, "missing parameter `connections`.");
+    }
+
+    #[test]
+    fn calls_parts_with_and_without_params() {
+        let params = get_raw_params();
+        assert_eq!(solve(part_without_params, &params), Some("3".into()));
+        assert_eq!(solve(part_with_params, &params), Some("30".into()));
+    }
+}
@@ -0,0 +1,171 @@
+/// Module that defines the build profiles `time --profiles` benchmarks solutions with.
+/// The profiles themselves are declared in `Cargo.toml`.
+use std::time::Duration;
+
+use crate::template::{Day, all_days, timings::Timings};
+
+/// A cargo profile, plus compiler flags that cannot be set in `Cargo.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildProfile {
+    pub name: &'static str,
+    pub rustflags: Option<&'static str>,
+}
+
+pub const RELEASE: BuildProfile = BuildProfile {
+    name: "release",
+    rustflags: None,
+};
+
+/// The release build, followed by every variation it is compared against.
+pub const PROFILES: [BuildProfile; 4] = [
+    RELEASE,
+    BuildProfile {
+        name: "lto",
+        rustflags: None,
+    },
+    BuildProfile {
+        name: "native",
+        rustflags: Some("-C target-cpu=native"),
+    },
+    BuildProfile {
+        name: "cgu1-abort",
+        rustflags: None,
+    },
+];
+
+/// The fastest profile of a day. Runs that solved fewer parts than others are not considered.
+pub fn winner(runs: &[(BuildProfile, Timings)], day: Day) -> Option<(BuildProfile, f64)> {
+    let timings: Vec<_> = runs
+        .iter()
+        .filter_map(|(profile, timings)| {
+            let timing = timings.data.iter().find(|t| t.day == day)?;
+            let parts = usize::from(timing.part_1.is_some()) + usize::from(timing.part_2.is_some());
+            (parts > 0).then_some((*profile, parts, timing.total_nanos()))
+        })
+        .collect();
+
+    let parts = timings.iter().map(|(_, parts, _)| *parts).max()?;
+
+    timings
+        .into_iter()
+        .filter(|(_, p, _)| *p == parts)
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(profile, _, nanos)| (profile, nanos))
+}
+
+/// Timings of the winning profile of every day, with the profile name recorded.
+pub fn best(runs: &[(BuildProfile, Timings)]) -> Timings {
+    let data = all_days()
+        .filter_map(|day| {
+            let (profile, _) = winner(runs, day)?;
+            let (_, timings) = runs.iter().find(|(p, _)| *p == profile)?;
+            let mut timing = timings.data.iter().find(|t| t.day == day)?.clone();
+            timing.profile = Some(profile.name.into());
+            Some(timing)
+        })
+        .collect();
+
+    Timings { data }
+}
+
+fn nanos_to_duration(nanos: f64) -> Duration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Format the total time of every day per profile as an aligned table.
+pub fn format_table(runs: &[(BuildProfile, Timings)]) -> Vec<String> {
+    let mut header = format!("{:<4}", "Day");
+    for (profile, _) in runs {
+        header.push_str(&format!(" {:>11}", profile.name));
+    }
+    header.push_str(&format!(" {:>11}", "winner"));
+
+    let mut lines = vec![header];
+
+    for day in all_days() {
+        let Some((winner, _)) = winner(runs, day) else {
+            continue;
+        };
+
+        let mut line = format!("{:<4}", day.to_string());
+        for (_, timings) in runs {
+            let total = timings
+                .data
+                .iter()
+                .find(|t| t.day == day)
+                .map(|t| format!("{:.1?}", nanos_to_duration(t.total_nanos())));
+            line.push_str(&format!(" {:>11}", total.as_deref().unwrap_or("-")));
+        }
+        line.push_str(&format!(" {:>11}", winner.name));
+        lines.push(line);
+    }
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PROFILES, best, format_table, winner};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn timings(part_1: f64, part_2: Option<f64>) -> Timings {
+        let part = |nanos| PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+        };
+
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some(part(part_1)),
+                part_2: part_2.map(part),
+                profile: None,
+                variants: vec![],
+                status: [None; 2],
+            }],
+        }
+    }
+
+    fn get_mock_runs() -> Vec<(super::BuildProfile, Timings)> {
+        vec![
+            (PROFILES[0], timings(2e6, Some(2e6))),
+            (PROFILES[1], timings(1e6, Some(2e6))),
+            // fastest, but part 2 is missing.
+            (PROFILES[2], timings(1e5, None)),
+        ]
+    }
+
+    #[test]
+    fn picks_fastest_complete_profile() {
+        let (profile, nanos) = winner(&get_mock_runs(), day!(1)).unwrap();
+        assert_eq!(profile.name, "lto");
+        assert_eq!(nanos, 3e6);
+        assert!(winner(&get_mock_runs(), day!(2)).is_none());
+    }
+
+    #[test]
+    fn records_winning_profile() {
+        let best = best(&get_mock_runs());
+        assert_eq!(best.data.len(), 1);
+        assert_eq!(best.data[0].profile.as_deref(), Some("lto"));
+        assert_eq!(best.data[0].total_nanos(), 3e6);
+    }
+
+    #[test]
+    fn formats_table() {
+        assert_eq!(
+            format_table(&get_mock_runs()),
+            [
+                "Day      release         lto      native      winner",
+                "01         4.0ms       3.0ms     100.0µs         lto",
+            ]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+/// Module that parses the puzzle descriptions written by aoc-cli to `data/puzzles/NN.md`.
+use std::{env, fs, io};
+
+use crate::template::Day;
+
+static PART_TWO_MARKER: &str = "--- Part Two ---";
+
+/// A puzzle description, split into its parts.
+#[derive(Clone, Debug, Default)]
+pub struct Puzzle {
+    pub title: Option<String>,
+    pub parts: Vec<String>,
+}
+
+/// The example input and the expected answer found in one part of a puzzle description.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+#[must_use]
+pub fn get_path_for_puzzle(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+impl Puzzle {
+    /// Read the puzzle description for a day from `data/puzzles/`.
+    pub fn read(day: Day) -> Result<Self, io::Error> {
+        let cwd = env::current_dir()?;
+        let markdown = fs::read_to_string(cwd.join(get_path_for_puzzle(day)))?;
+        Ok(Self::parse(&markdown))
+    }
+
+    pub fn parse(markdown: &str) -> Self {
+        let title = markdown.lines().find_map(parse_title);
+
+        let parts = match markdown.find(PART_TWO_MARKER) {
+            Some(pos) => {
+                // cut at the start of the line so that heading prefixes stay with part two.
+                let line_start = markdown[..pos].rfind('\n').map_or(0, |i| i + 1);
+                vec![
+                    markdown[..line_start].to_string(),
+                    markdown[line_start..].to_string(),
+                ]
+            }
+            None => vec![markdown.to_string()],
+        };
+
+        Puzzle { title, parts }
+    }
+
+    /// Text of a single part (1 or 2), if it has been unlocked.
+    pub fn part(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)
+            .map(String::as_str)
+    }
+
+    /// Find the example input and expected answer of a part.
+    ///
+    /// The example input is the first code block that is introduced by a paragraph mentioning an example.
+    /// Part one falls back to its first code block, part two only reports an input if it differs from part one.
+    /// The expected answer is the last highlighted code span of the part.
+    pub fn example(&self, part: u8) -> Example {
+        let Some(text) = self.part(part) else {
+            return Example::default();
+        };
+
+        let blocks = code_blocks(text);
+        let mut input = blocks
+            .iter()
+            .find(|(intro, _)| intro.to_lowercase().contains("example"))
+            .or(if part == 1 { blocks.first() } else { None })
+            .map(|(_, block)| block.clone());
+
+        if part > 1 && input.is_some() && input == self.example(1).input {
+            input = None;
+        }
+
+        Example {
+            input,
+            answer: emphasized_code(text).pop(),
+        }
+    }
+}
+
+fn parse_title(line: &str) -> Option<String> {
+    let line = line.trim_start_matches(['\\', '#', ' ']);
+    let rest = line.strip_prefix("--- Day ")?.strip_suffix(" ---")?;
+    let (_, title) = rest.split_once(": ")?;
+    Some(title.trim().to_string())
+}
+
+/// Collect fenced code blocks, paired with the text that precedes them.
+fn code_blocks(text: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    let mut intro: Vec<&str> = vec![];
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in text.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+        match (&mut block, is_fence) {
+            (None, true) => block = Some(vec![]),
+            (None, false) => {
+                if line.trim().is_empty() {
+                    if !intro.is_empty() {
+                        intro.push(line);
+                    }
+                } else {
+                    // only keep the paragraph directly preceding the block.
+                    if intro.last().is_some_and(|l| l.trim().is_empty()) {
+                        intro.clear();
+                    }
+                    intro.push(line);
+                }
+            }
+            (Some(lines), false) => lines.push(line),
+            (Some(lines), true) => {
+                let mut content = lines.join("\n");
+                content.push('\n');
+                blocks.push((intro.join(" "), content));
+                intro.clear();
+                block = None;
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Collect code spans that are highlighted, e.g. `` `*42*` `` or ``*`42`*``.
+pub fn emphasized_code(text: &str) -> Vec<String> {
+    let mut results = vec![];
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['`', '*']) {
+        let candidate = &rest[pos..];
+        let found = ["`**", "`*", "**`", "*`"].iter().find_map(|open| {
+            let inner = candidate.strip_prefix(open)?;
+            let close: String = open.chars().rev().collect();
+            let end = inner.find(&close)?;
+            let value = &inner[..end];
+            let is_valid = !value.is_empty() && !value.contains(['`', '*', '\n']);
+            is_valid.then(|| (value.to_string(), open.len() + end + close.len()))
+        });
+
+        match found {
+            Some((value, len)) => {
+                results.push(value);
+                rest = &candidate[len..];
+            }
+            None => rest = &candidate[1..],
+        }
+    }
+
+    results
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Puzzle, emphasized_code};
+
+    fn get_mock_markdown() -> String {
+        [
+            "\\--- Day 4: Printing Department ---",
+            "----------",
+            "",
+            "The rolls are arranged on a large grid.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "..@@",
+            "@@.@",
+            "```",
+            "",
+            "In this example, there are `*13*` rolls of paper that can be accessed.",
+            "",
+            "*How many rolls of paper can be accessed?*",
+            "",
+            "\\--- Part Two ---",
+            "----------",
+            "",
+            "Now, consider this larger example:",
+            "",
+            "```",
+            "@@@@",
+            "```",
+            "",
+            "Removed after the first step:",
+            "",
+            "```",
+            "..xx",
+            "```",
+            "",
+            "In total, `*43*` rolls of paper can be removed.",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn parses_title() {
+        let puzzle = Puzzle::parse(&get_mock_markdown());
+        assert_eq!(puzzle.title, Some("Printing Department".into()));
+        assert_eq!(puzzle.parts.len(), 2);
+        assert!(puzzle.part(2).unwrap().starts_with("\\--- Part Two ---"));
+    }
+
+    #[test]
+    fn finds_examples() {
+        let puzzle = Puzzle::parse(&get_mock_markdown());
+        assert_eq!(
+            puzzle.example(1),
+            Example {
+                input: Some("..@@\n@@.@\n".into()),
+                answer: Some("13".into())
+            }
+        );
+        assert_eq!(
+            puzzle.example(2),
+            Example {
+                input: Some("@@@@\n".into()),
+                answer: Some("43".into())
+            }
+        );
+    }
+
+    #[test]
+    fn handles_locked_part_two() {
+        let markdown = get_mock_markdown();
+        let part_one = markdown.split("\\--- Part Two").next().unwrap();
+        let puzzle = Puzzle::parse(part_one);
+        assert_eq!(puzzle.parts.len(), 1);
+        assert_eq!(puzzle.example(2), Example::default());
+    }
+
+    #[test]
+    fn skips_part_two_example_equal_to_part_one() {
+        let markdown = "For example:\n\n```\n1\n```\n\n\\--- Part Two ---\n\nThe same example:\n\n```\n1\n```\n\nNow it is `*2*`.";
+        let puzzle = Puzzle::parse(markdown);
+        assert_eq!(puzzle.example(2).input, None);
+        assert_eq!(puzzle.example(2).answer, Some("2".into()));
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        let text = "a `*1*` b *`2`* c `3` d *four* e `**5**` f";
+        assert_eq!(emphasized_code(text), vec!["1", "2", "5"]);
+    }
+}
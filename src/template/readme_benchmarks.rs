@@ -1,9 +1,9 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, str::FromStr};
 
 use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::timings::{Timing, Timings};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,8 +21,8 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
@@ -30,8 +30,9 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Find the section enclosed by two occurrences of `marker`.
+pub(super) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,25 +53,78 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Which implementations the benchmark table lists for parts with several variants.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VariantDisplay {
+    #[default]
+    Fastest,
+    All,
+}
+
+impl FromStr for VariantDisplay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fastest" => Ok(VariantDisplay::Fastest),
+            "all" => Ok(VariantDisplay::All),
+            _ => Err(format!(
+                "unknown variant display \"{s}\", expected one of: fastest, all."
+            )),
+        }
+    }
+}
+
+fn format_part(timing: &Timing, part: u8, display: VariantDisplay) -> String {
+    let variants = timing.variants(part);
+
+    match (variants.as_slice(), display) {
+        ([], _) => format!(
+            "`{}`",
+            timing
+                .part(part)
+                .map_or_else(|| "-".into(), ToString::to_string)
+        ),
+        ([fastest, ..], VariantDisplay::Fastest) => {
+            format!("`{}` ({})", fastest.timing, fastest.name)
+        }
+        (variants, VariantDisplay::All) => variants
+            .iter()
+            .map(|v| format!("`{}` ({})", v.timing, v.name))
+            .collect::<Vec<_>>()
+            .join("<br>"),
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+    display: VariantDisplay,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if let Some(chart_path) = chart_path {
+        lines.push(format!("![Benchmarks](./{chart_path})"));
+        lines.push(String::new());
+    }
+
+    lines.extend([
         "| Day | Part 1 | Part 2 |".into(),
         "| :---: | :---: | :---:  |".into(),
-    ];
+    ]);
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(&timing, 1, display),
+            format_part(&timing, 2, display)
         ));
     }
 
@@ -81,47 +135,75 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    chart_path: Option<&str>,
+    display: VariantDisplay,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, total_millis, chart_path, display);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the benchmark table in the readme, embedding the chart if one was generated.
+pub fn update(
+    timings: Timings,
+    chart_path: Option<&str>,
+    display: VariantDisplay,
+) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings, total_millis, chart_path, display)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{MARKER, VariantDisplay, update_content};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings, VariantTiming},
+    };
+
+    fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1: part(10e6),
+                    part_2: part(20e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1: part(30e6),
+                    part_2: part(40e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    part_1: part(40e6),
+                    part_2: part(50e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
             ],
         }
@@ -131,28 +213,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +277,14 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +293,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +304,50 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn embeds_chart() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            get_mock_timings(),
+            190.0,
+            Some(".assets/chart.svg"),
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
+        assert!(s.contains("## Benchmarks\n\n![Benchmarks](./.assets/chart.svg)\n\n| Day |"));
+    }
+
+    #[test]
+    fn format_variants() {
+        let mut timings = get_mock_timings();
+        timings.data[0].variants = vec![
+            VariantTiming {
+                part: 2,
+                name: "lp".into(),
+                timing: part(30e6).unwrap(),
+            },
+            VariantTiming {
+                part: 2,
+                name: "bnb".into(),
+                timing: part(20e6).unwrap(),
+            },
+        ];
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            timings.clone(),
+            190.0,
+            None,
+            VariantDisplay::Fastest,
+        )
+        .unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` (bnb) |"));
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, None, VariantDisplay::All).unwrap();
+        assert!(s.contains("| `10.0ms` | `20.0ms` (bnb)<br>`30.0ms` (lp) |"));
+    }
 }
@@ -0,0 +1,191 @@
+/// Module that updates the readme with the stars earned, based on the local submission log.
+/// The table has the same format as the one `aoc-readme-stars` generates.
+use std::{collections::HashSet, fs};
+
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::submissions::Submissions;
+use crate::template::{Day, all_days, aoc_cli};
+
+static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The stars of an existing table, so that stars earned before the submission log existed are kept.
+fn parse_stars(table: &str) -> HashSet<(Day, u8)> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<_> = line.split('|').map(str::trim).collect();
+            let [_, label, part_one, part_two, ..] = cells[..] else {
+                return None;
+            };
+
+            let (_, number) = label.split_once("Day ")?;
+            let number: String = number.chars().take_while(char::is_ascii_digit).collect();
+            let day = Day::new(number.parse().ok()?)?;
+
+            Some(
+                [(1, part_one), (2, part_two)]
+                    .into_iter()
+                    .filter(|(_, cell)| cell.contains('⭐'))
+                    .map(move |(part, _)| (day, part)),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+fn construct_table(
+    prefix: &str,
+    submissions: &Submissions,
+    existing: &HashSet<(Day, u8)>,
+    year: Option<u16>,
+) -> String {
+    let header = match year {
+        Some(year) => format!("{prefix} {year} Results"),
+        None => format!("{prefix} Results"),
+    };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    let has_star = |day, part| submissions.has_star(day, part) || existing.contains(&(day, part));
+    let star = |day, part| if has_star(day, part) { "⭐" } else { " " };
+
+    for day in all_days() {
+        if !has_star(day, 1) && !has_star(day, 2) {
+            continue;
+        }
+
+        let label = match year {
+            Some(year) => format!(
+                "[Day {}](https://adventofcode.com/{year}/day/{})",
+                day.into_inner(),
+                day.into_inner()
+            ),
+            None => format!("Day {}", day.into_inner()),
+        };
+
+        lines.push(format!("| {label} | {} | {} |", star(day, 1), star(day, 2)));
+    }
+
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    submissions: &Submissions,
+    year: Option<u16>,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let existing = parse_stars(&s[positions.pos_start..positions.pos_end]);
+    let table = construct_table("##", submissions, &existing, year);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update(submissions: &Submissions) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, submissions, aoc_cli::get_year())?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MARKER, update_content};
+    use crate::{
+        day,
+        template::submissions::{Submission, Submissions},
+    };
+
+    fn get_mock_submissions() -> Submissions {
+        let submission = |day, part, correct| Submission {
+            day,
+            part,
+            answer: "1".into(),
+            correct,
+        };
+
+        Submissions {
+            data: vec![
+                submission(day!(1), 1, true),
+                submission(day!(1), 2, true),
+                submission(day!(2), 1, false),
+                submission(day!(3), 1, true),
+            ],
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &get_mock_submissions(), Some(2025)).unwrap();
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nold table\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_submissions(), Some(2025)).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_stars_without_year() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &get_mock_submissions(), None).unwrap();
+        assert!(s.contains("## Results"));
+        assert!(s.contains("| Day 1 | ⭐ | ⭐ |"));
+    }
+
+    #[test]
+    fn keeps_existing_stars() {
+        let mut s = [
+            MARKER,
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ |   |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ | ⭐ |",
+            "| Day 12 | ⭐ | ⭐ |",
+            MARKER,
+        ]
+        .join("\n");
+
+        let submissions = Submissions {
+            data: vec![Submission {
+                day: day!(1),
+                part: 2,
+                answer: "1".into(),
+                correct: true,
+            }],
+        };
+        update_content(&mut s, &submissions, Some(2025)).unwrap();
+
+        assert!(s.contains("| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 12](https://adventofcode.com/2025/day/12) | ⭐ | ⭐ |"));
+        assert!(!s.contains("Day 3"));
+    }
+}
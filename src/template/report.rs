@@ -0,0 +1,434 @@
+/// Module that renders a self-contained HTML report of benchmarks and progress.
+/// The page has no external dependencies: styles and the sort / filter script are embedded.
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use crate::template::{
+    Day, all_days,
+    allocations::Allocations,
+    aoc_cli,
+    puzzle::Puzzle,
+    readme_benchmarks::get_path_for_bin,
+    submissions::Submissions,
+    timings::{Timing, Timings, TimingsHistory},
+};
+
+const SPARKLINE_WIDTH: f64 = 80.0;
+const SPARKLINE_HEIGHT: f64 = 16.0;
+
+const STYLE: &str = r"
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 4px 10px; border-bottom: 1px solid #ddd; text-align: left; }
+th { cursor: pointer; user-select: none; }
+th.asc::after { content: ' ▲'; }
+th.desc::after { content: ' ▼'; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+.ok { color: #2a8a2a; }
+.bad { color: #c0392b; }
+.muted { color: #999; }
+input { margin-bottom: 1em; padding: 4px; width: 20em; }
+";
+
+const SCRIPT: &str = r"
+const table = document.getElementById('report');
+const body = table.tBodies[0];
+document.getElementById('filter').addEventListener('input', (e) => {
+  const query = e.target.value.toLowerCase();
+  for (const row of body.rows) {
+    row.hidden = !row.textContent.toLowerCase().includes(query);
+  }
+});
+table.tHead.querySelectorAll('th').forEach((th, column) => {
+  th.addEventListener('click', () => {
+    const asc = !th.classList.contains('asc');
+    table.tHead.querySelectorAll('th').forEach((h) => h.classList.remove('asc', 'desc'));
+    th.classList.add(asc ? 'asc' : 'desc');
+    const value = (row) => row.cells[column].dataset.value ?? '';
+    const rows = Array.from(body.rows).sort((a, b) => {
+      const [x, y] = [value(a), value(b)];
+      const order = x !== '' && y !== '' && !isNaN(x) && !isNaN(y)
+        ? Number(x) - Number(y)
+        : (x === '') - (y === '') || x.localeCompare(y);
+      return asc ? order : -order;
+    });
+    rows.forEach((row) => body.appendChild(row));
+  });
+});
+";
+
+/// Everything the report is built from.
+#[derive(Clone, Debug, Default)]
+pub struct ReportData {
+    pub year: Option<u16>,
+    pub titles: HashMap<Day, String>,
+    /// Days that have a solution in `src/bin/`.
+    pub solutions: Vec<Day>,
+    pub timings: Timings,
+    pub history: TimingsHistory,
+    pub submissions: Submissions,
+    pub allocations: Allocations,
+}
+
+impl ReportData {
+    /// Collect the stored metadata of all days.
+    pub fn collect() -> Self {
+        let titles = all_days()
+            .filter_map(|day| Some((day, Puzzle::read(day).ok()?.title?)))
+            .collect();
+
+        let solutions = all_days()
+            .filter(|day| Path::new(&get_path_for_bin(*day)).exists())
+            .collect();
+
+        ReportData {
+            year: aoc_cli::get_year(),
+            titles,
+            solutions,
+            timings: Timings::read_from_file(),
+            history: TimingsHistory::read_from_file(),
+            submissions: Submissions::read_from_file(),
+            allocations: Allocations::read_from_file(),
+        }
+    }
+
+    /// Days that have any data attached to them.
+    fn days(&self) -> Vec<Day> {
+        all_days()
+            .filter(|day| {
+                self.titles.contains_key(day)
+                    || self.solutions.contains(day)
+                    || self.timings.data.iter().any(|t| t.day == *day)
+                    || self.submissions.data.iter().any(|s| s.day == *day)
+            })
+            .collect()
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// A table cell with an optional sort key.
+fn cell(html: &str, value: Option<&str>, class: Option<&str>) -> String {
+    let value = value
+        .map(|v| format!(r#" data-value="{}""#, escape(v)))
+        .unwrap_or_default();
+    let class = class
+        .map(|c| format!(r#" class="{c}""#))
+        .unwrap_or_default();
+    format!("<td{class}{value}>{html}</td>")
+}
+
+/// Inline SVG of the total time of a day across all stored benchmark runs.
+fn sparkline(values: &[f64]) -> String {
+    if values.len() < 2 {
+        return String::new();
+    }
+
+    let max = values.iter().copied().fold(f64::MIN, f64::max);
+    let min = values.iter().copied().fold(f64::MAX, f64::min);
+    let range = (max - min).max(f64::EPSILON);
+
+    #[allow(clippy::cast_precision_loss)]
+    let step = SPARKLINE_WIDTH / (values.len() - 1) as f64;
+    let points: Vec<String> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = i as f64 * step;
+            let y = SPARKLINE_HEIGHT - 1.0 - (v - min) / range * (SPARKLINE_HEIGHT - 2.0);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+
+    format!(
+        r#"<svg width="{SPARKLINE_WIDTH}" height="{SPARKLINE_HEIGHT}"><polyline points="{}" fill="none" stroke="currentColor"/></svg>"#,
+        points.join(" ")
+    )
+}
+
+fn status_cell(submissions: &Submissions, day: Day, part: u8) -> String {
+    let attempts = submissions
+        .data
+        .iter()
+        .filter(|s| s.day == day && s.part == part)
+        .count();
+
+    match submissions.accepted(day, part) {
+        Some(answer) => cell(
+            &format!(r#"<span title="{}">⭐</span>"#, escape(answer)),
+            Some("2"),
+            Some("ok"),
+        ),
+        // solved before the submission log existed, so the answer is unknown.
+        None if submissions.has_star(day, part) => cell("⭐", Some("2"), Some("ok")),
+        None if attempts > 0 => cell(&format!("✗ {attempts} wrong"), Some("1"), Some("bad")),
+        None => cell("-", Some("0"), Some("muted")),
+    }
+}
+
+/// Render the report. Source links are prefixed with `source_prefix`, relative to the report file.
+pub fn render(data: &ReportData, source_prefix: &str) -> String {
+    let mut rows = String::new();
+
+    for day in data.days() {
+        let n = day.into_inner();
+        let timing = data.timings.data.iter().find(|t| t.day == day);
+        let mut row = String::new();
+
+        row.push_str(&cell(&n.to_string(), Some(&n.to_string()), None));
+
+        let title = data.titles.get(&day).map_or("-", String::as_str);
+        let title_html = match data.year {
+            Some(year) => format!(
+                r#"<a href="https://adventofcode.com/{year}/day/{n}">{}</a>"#,
+                escape(title)
+            ),
+            None => escape(title),
+        };
+        row.push_str(&cell(&title_html, Some(title), None));
+
+        for part in 1..=2 {
+            row.push_str(&status_cell(&data.submissions, day, part));
+        }
+
+        for part in 1..=2 {
+            let timing = timing.and_then(|t| t.part(part));
+            row.push_str(&cell(
+                &timing.map_or_else(|| "-".into(), ToString::to_string),
+                timing.map(|t| t.nanos.to_string()).as_deref(),
+                Some("num"),
+            ));
+        }
+
+        let total = timing.map(Timing::total_nanos);
+        row.push_str(&cell(
+            &total.map_or_else(|| "-".into(), |t| format!("{:.3}ms", t / 1e6)),
+            total.map(|t| t.to_string()).as_deref(),
+            Some("num"),
+        ));
+
+        let history: Vec<f64> = data
+            .history
+            .for_day(day)
+            .map(|(_, t)| t.total_nanos())
+            .collect();
+        row.push_str(&cell(&sparkline(&history), None, None));
+
+        for part in 1..=2 {
+            let allocation = data.allocations.get(day, part);
+            let html = allocation.map_or_else(
+                || "-".into(),
+                |a| {
+                    format!(
+                        r#"<span title="{} in {} blocks">{}</span>"#,
+                        format_bytes(a.total_bytes),
+                        a.total_blocks,
+                        format_bytes(a.max_bytes)
+                    )
+                },
+            );
+            row.push_str(&cell(
+                &html,
+                allocation.map(|a| a.max_bytes.to_string()).as_deref(),
+                Some("num"),
+            ));
+        }
+
+        let source = if data.solutions.contains(&day) {
+            let path = get_path_for_bin(day);
+            let path = path.trim_start_matches("./");
+            format!(r#"<a href="{}{path}">{path}</a>"#, escape(source_prefix))
+        } else {
+            "-".into()
+        };
+        row.push_str(&cell(&source, None, None));
+
+        let _ = writeln!(rows, "<tr>{row}</tr>");
+    }
+
+    let heading = match data.year {
+        Some(year) => format!("Advent of Code {year}"),
+        None => "Advent of Code".into(),
+    };
+
+    let stars: usize = data
+        .days()
+        .iter()
+        .map(|day| {
+            (1..=2)
+                .filter(|p| data.submissions.has_star(*day, *p))
+                .count()
+        })
+        .sum();
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{heading}</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<h1>{heading}</h1>
+<p>{stars} ⭐ · total {total:.2}ms</p>
+<input id="filter" type="search" placeholder="Filter days">
+<table id="report">
+<thead><tr><th>Day</th><th>Title</th><th>Part 1</th><th>Part 2</th><th>Time 1</th><th>Time 2</th><th>Total</th><th>History</th><th>Peak heap 1</th><th>Peak heap 2</th><th>Source</th></tr></thead>
+<tbody>
+{rows}</tbody>
+</table>
+<script>{SCRIPT}</script>
+</body>
+</html>
+"#,
+        total = data.timings.total_millis(),
+    );
+    html
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{ReportData, escape, format_bytes, render, sparkline};
+    use crate::{
+        day,
+        template::{
+            allocations::{Allocation, Allocations},
+            submissions::{Submission, Submissions},
+            timings::{HistoryEntry, PartTiming, Timing, Timings, TimingsHistory},
+        },
+    };
+
+    fn get_mock_data() -> ReportData {
+        let timing = |nanos| Timing {
+            day: day!(1),
+            part_1: Some(PartTiming {
+                nanos,
+                samples: 10,
+                stats: None,
+            }),
+            part_2: None,
+            profile: None,
+            variants: vec![],
+            status: [None; 2],
+        };
+
+        ReportData {
+            year: Some(2025),
+            titles: HashMap::from([(day!(1), "Secret <Entrance>".into())]),
+            solutions: vec![day!(1), day!(2)],
+            timings: Timings {
+                data: vec![timing(30_000.0)],
+            },
+            history: TimingsHistory {
+                runs: vec![
+                    HistoryEntry {
+                        timestamp: 1,
+                        timings: Timings {
+                            data: vec![timing(50_000.0)],
+                        },
+                    },
+                    HistoryEntry {
+                        timestamp: 2,
+                        timings: Timings {
+                            data: vec![timing(30_000.0)],
+                        },
+                    },
+                ],
+            },
+            submissions: Submissions {
+                data: vec![
+                    Submission {
+                        day: day!(1),
+                        part: 1,
+                        answer: "3".into(),
+                        correct: true,
+                    },
+                    Submission {
+                        day: day!(2),
+                        part: 1,
+                        answer: "7".into(),
+                        correct: false,
+                    },
+                ],
+            },
+            allocations: Allocations {
+                data: vec![Allocation {
+                    day: day!(1),
+                    part: 1,
+                    total_bytes: 4096,
+                    total_blocks: 3,
+                    max_bytes: 2048,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(
+            escape(r#"<a href="x">&'"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&#39;"
+        );
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn draws_sparklines_for_multiple_runs() {
+        assert_eq!(sparkline(&[1.0]), "");
+        assert!(sparkline(&[2.0, 1.0]).contains(r#"points="0.0,1.0 80.0,15.0""#));
+    }
+
+    #[test]
+    fn renders_rows_per_day() {
+        let html = render(&get_mock_data(), "../");
+        assert_eq!(html.matches("<tr>").count(), 3);
+        assert!(html.contains("Secret &lt;Entrance&gt;"));
+        assert!(html.contains(r#"href="../src/bin/02.rs""#));
+        assert!(html.contains("✗ 1 wrong"));
+        assert!(html.contains(r#"data-value="2048""#));
+        assert!(html.contains("<polyline"));
+        assert!(html.contains("1 ⭐"));
+    }
+}
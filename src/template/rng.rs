@@ -0,0 +1,56 @@
+/// A small, seeded pseudo-random number generator (splitmix64) for input generators.
+/// The same seed always yields the same input, so that scaling benchmarks are reproducible.
+use std::ops::Range;
+
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`. The range must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(
+            !range.is_empty(),
+            "cannot pick a number from an empty range"
+        );
+        let span = range.end.abs_diff(range.start);
+        #[allow(clippy::cast_possible_wrap)]
+        range.start.wrapping_add((self.next_u64() % span) as i64)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn is_deterministic() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-5..5).contains(&rng.range(-5..5)));
+        }
+    }
+}
@@ -1,13 +1,28 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, path::Path};
 
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    profiles::{self, BuildProfile},
+    timings::{PartStatus, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Run the solutions of all given days. The returned timings hold the status of every part,
+/// and benchmark times when `is_timed` is set.
+pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Timings {
+    let profile = is_release.then_some(&profiles::RELEASE);
+    run_multi_in(Path::new("."), days_to_run, profile, is_timed)
+}
+
+/// Like [`run_multi`], but runs the solutions of the checkout at `root`, built with `profile`.
+/// Without a profile, solutions are built in debug mode.
+pub fn run_multi_in(
+    root: &Path,
+    days_to_run: &HashSet<Day>,
+    profile: Option<&BuildProfile>,
+    is_timed: bool,
+) -> Timings {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,7 +39,7 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output = child_commands::run_solution(root, day, is_timed, profile).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
@@ -34,16 +49,47 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             }
         });
 
+    let timings = Timings { data: timings };
+
     if is_timed {
-        let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
     }
+
+    timings
+}
+
+/// Count the parts per status, followed by every part that did not return an answer.
+pub fn format_summary(timings: &Timings) -> Vec<String> {
+    let statuses: Vec<(Day, u8, PartStatus)> = timings
+        .data
+        .iter()
+        .flat_map(|t| (1..=2).filter_map(move |part| Some((t.day, part, t.status(part)?))))
+        .collect();
+
+    let counts: Vec<String> = [
+        PartStatus::Ok,
+        PartStatus::None,
+        PartStatus::Error,
+        PartStatus::Panic,
+    ]
+    .iter()
+    .map(|status| {
+        let count = statuses.iter().filter(|(_, _, s)| s == status).count();
+        format!("{count} {status}")
+    })
+    .collect();
+
+    let mut lines = vec![counts.join(", ")];
+    for (day, part, status) in statuses {
+        if status != PartStatus::Ok {
+            lines.push(format!("  Day {day} Part {part}: {status}"));
+        }
+    }
+
+    lines
 }
 
 #[allow(dead_code)]
@@ -67,43 +113,67 @@ pub fn get_path_for_bin(day: Day) -> String {
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
+    use super::{BuildProfile, Error, get_path_for_bin};
     use crate::template::Day;
+    use crate::template::timings::{PartStatus, PartTiming, Stats, VariantTiming};
     use std::{
+        env,
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day, in the checkout at `root`.
+    pub fn run_solution(
+        root: &Path,
+        day: Day,
+        is_timed: bool,
+        profile: Option<&BuildProfile>,
+    ) -> Result<Vec<String>, Error> {
+        // mirror `--time` flag to child invocations.
+        let bin_args: &[&str] = if is_timed { &["--time"] } else { &[] };
+        run_solution_with_args(root, day, profile, bin_args)
+    }
+
+    /// Like [`run_solution`], but passes `bin_args` to the solution bin.
+    pub fn run_solution_with_args(
+        root: &Path,
+        day: Day,
+        profile: Option<&BuildProfile>,
+        bin_args: &[&str],
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !root.join(get_path_for_bin(day)).exists() {
             return Ok(vec![]);
         }
 
         let day_padded = day.to_string();
         let mut args = vec!["run", "--quiet", "--bin", &day_padded];
 
-        if is_release {
-            args.push("--release");
+        if let Some(profile) = profile {
+            args.push("--profile");
+            args.push(profile.name);
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
+        if !bin_args.is_empty() {
             args.push("--");
-            args.push("--time");
+            args.extend(bin_args);
         }
 
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut cmd = Command::new("cargo");
+        cmd.args(&args).current_dir(root);
+
+        if let Some(flags) = profile.and_then(|p| p.rustflags) {
+            let rustflags =
+                env::var("RUSTFLAGS").map_or_else(|_| flags.into(), |f| format!("{f} {flags}"));
+            cmd.env("RUSTFLAGS", rustflags);
+        }
+
+        let mut cmd = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
@@ -128,65 +198,174 @@ pub mod child_commands {
         Ok(output)
     }
 
+    /// Run the release build of a solution without forwarding its output, returning its stdout lines.
+    pub fn run_solution_output(day: Day) -> Result<Vec<String>, Error> {
+        let output = Command::new("cargo")
+            .args(["run", "--quiet", "--release", "--bin", &day.to_string()])
+            .stderr(Stdio::inherit())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(String::from)
+            .collect())
+    }
+
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
-            total_nanos: 0_f64,
+            profile: None,
+            variants: vec![],
+            status: [1, 2].map(|part| parse_status(output, part)),
         };
 
-        output
+        let parsed: Vec<(u8, Option<&str>, PartTiming)> = output
             .iter()
             .filter_map(|l| {
-                if !l.contains(" samples)") {
+                if !l.contains(" samples") {
                     return None;
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
+                let Some(timing) = parse_time(l) else {
                     eprintln!("Could not parse timings from line: {l}");
                     return None;
                 };
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                // parts with several variants are labeled like "Part 2 (lp)".
+                let label = l.split(':').next()?;
+                let part = if label.contains("Part 1") {
+                    1
+                } else if label.contains("Part 2") {
+                    2
+                } else {
+                    return None;
+                };
+                let variant = label
+                    .split_once('(')
+                    .and_then(|(_, name)| name.strip_suffix(')'));
+
+                Some((part, variant, timing))
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            .collect();
 
-                timings.total_nanos += nanos;
-            });
+        for part in 1..=2 {
+            let fastest = parsed
+                .iter()
+                .filter(|(p, _, _)| *p == part)
+                .map(|(_, _, timing)| *timing)
+                .min_by(|a, b| a.nanos.total_cmp(&b.nanos));
+
+            if part == 1 {
+                timings.part_1 = fastest;
+            } else {
+                timings.part_2 = fastest;
+            }
+        }
+
+        timings.variants = parsed
+            .into_iter()
+            .filter_map(|(part, variant, timing)| {
+                Some(VariantTiming {
+                    part,
+                    name: variant?.to_string(),
+                    timing,
+                })
+            })
+            .collect();
 
         timings
     }
 
+    /// Status of a part from the lines of all its variants, the worst one winning.
+    /// Lines are e.g. "Part 1: 42 (1.0ms)", "Part 1: ✖", "Part 2 (lp): panicked at ..." or "Part 2: error: ...".
+    fn parse_status(output: &[String], part: u8) -> Option<PartStatus> {
+        let label = format!("Part {part}");
+
+        output
+            .iter()
+            .filter_map(|line| {
+                // intermediate output is overwritten with a carriage return.
+                let line = line.rsplit('\r').next()?;
+                let (line_label, text) = line.split_once(": ")?;
+                let is_part = line_label == label
+                    || line_label.starts_with(&format!("{label} ("))
+                    || line_label
+                        .strip_prefix(&label)
+                        .is_some_and(|rest| rest.starts_with(" ["));
+                if !is_part {
+                    return None;
+                }
+
+                Some(if text.starts_with("panicked") {
+                    PartStatus::Panic
+                } else if text.starts_with("error:") {
+                    PartStatus::Error
+                } else if text.starts_with('✖') {
+                    PartStatus::None
+                } else {
+                    PartStatus::Ok
+                })
+            })
+            .max_by_key(|status| match status {
+                PartStatus::Ok => 0,
+                PartStatus::None => 1,
+                PartStatus::Error => 2,
+                PartStatus::Panic => 3,
+            })
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
+    /// Parse a duration in `Debug` format, e.g. "46.1µs", to nanoseconds.
+    pub fn parse_duration(s: &str) -> Option<f64> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
+        match s {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+        }
+    }
 
-        Some((str_timing, parsed_timing))
+    /// Parse the last parenthesized group of a line, e.g. "(46.1µs @ 100 samples, σ 2.3µs, min 40.0µs, max 60.2µs)".
+    pub fn parse_time(line: &str) -> Option<PartTiming> {
+        let group = line.trim_end().rsplit_once('(')?.1.strip_suffix(')')?;
+        let mut fields = group.split(", ");
+
+        let (duration, samples) = fields.next()?.split_once('@')?;
+        let nanos = parse_duration(duration.trim())?;
+        let samples = samples.trim().strip_suffix(" samples")?.parse().ok()?;
+
+        let (mut min, mut max, mut stddev) = (None, None, None);
+        for field in fields {
+            let (key, value) = field.split_once(' ')?;
+            let value = parse_duration(value.trim());
+            match key {
+                "min" => min = value,
+                "max" => max = value,
+                "σ" => stddev = value,
+                _ => {}
+            }
+        }
+
+        let stats = match (min, max, stddev) {
+            (Some(min_nanos), Some(max_nanos), Some(stddev_nanos)) => Some(Stats {
+                min_nanos,
+                max_nanos,
+                stddev_nanos,
+            }),
+            _ => None,
+        };
+
+        Some(PartTiming {
+            nanos,
+            samples,
+            stats,
+        })
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -207,7 +386,7 @@ pub mod child_commands {
     mod tests {
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::{day, template::timings::PartStatus};
 
         #[test]
         fn parses_execution_times() {
@@ -219,9 +398,12 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos(), 74130074.13_f64);
+            let part_1 = res.part_1.unwrap();
+            assert_approx_eq!(part_1.nanos, 74.13_f64);
+            assert_eq!(part_1.samples, 100000);
+            assert_eq!(part_1.stats, None);
+            assert_approx_eq!(res.part_2.unwrap().nanos, 74130000_f64);
         }
 
         #[test]
@@ -234,9 +416,58 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos(), 2100000000_f64);
+            assert_approx_eq!(res.part_1.unwrap().nanos, 2e9);
+            assert_approx_eq!(res.part_2.unwrap().nanos, 1e8);
+        }
+
+        #[test]
+        fn parses_stats() {
+            let res = parse_exec_time(
+                &["Part 1: 3 (46.1µs @ 100 samples, σ 2.3µs, min 40.0µs, max 60.2µs)".into()],
+                day!(1),
+            );
+            let stats = res.part_1.unwrap().stats.unwrap();
+            assert_approx_eq!(stats.stddev_nanos, 2300_f64);
+            assert_approx_eq!(stats.min_nanos, 40000_f64);
+            assert_approx_eq!(stats.max_nanos, 60200_f64);
+        }
+
+        #[test]
+        fn parses_variants() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 7 (1.0ms @ 10 samples)".into(),
+                    "Part 2 (lp): 33 (3.0ms @ 10 samples)".into(),
+                    "Part 2 (bnb): 33 (2.0ms @ 10 samples)".into(),
+                ],
+                day!(10),
+            );
+            assert_approx_eq!(res.part_1.unwrap().nanos, 1e6);
+            assert_approx_eq!(res.part_2.unwrap().nanos, 2e6);
+            assert_eq!(res.variants.len(), 2);
+            assert_eq!(res.variants[0].name, "lp");
+            assert_eq!(res.variants(2)[0].name, "bnb");
+        }
+
+        #[test]
+        fn parses_statuses() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 7 > benching\rPart 1: panicked at src/bin/10.rs:3:5: oops".into(),
+                    "Part 2 (lp): 33 (3.0ms @ 10 samples)".into(),
+                    "Part 2 (bnb): 32 (2.0ms @ 10 samples)".into(),
+                    "Part 2: error: variants disagree (lp: 33, bnb: 32)".into(),
+                ],
+                day!(10),
+            );
+            assert_eq!(
+                res.status,
+                [Some(PartStatus::Panic), Some(PartStatus::Error)]
+            );
+
+            let res = parse_exec_time(&["Part 1: ✖        ".into()], day!(1));
+            assert_eq!(res.status, [Some(PartStatus::None), None]);
         }
 
         #[test]
@@ -249,9 +480,48 @@ pub mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_approx_eq!(res.total_nanos(), 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_summary;
+    use crate::{
+        day,
+        template::timings::{PartStatus, Timing, Timings},
+    };
+
+    #[test]
+    fn summarizes_statuses() {
+        let timing = |day, status| Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            profile: None,
+            variants: vec![],
+            status,
+        };
+        let timings = Timings {
+            data: vec![
+                timing(day!(1), [Some(PartStatus::Ok), Some(PartStatus::Ok)]),
+                timing(day!(2), [Some(PartStatus::Ok), Some(PartStatus::Panic)]),
+                timing(day!(3), [Some(PartStatus::None), None]),
+            ],
+        };
+
+        assert_eq!(
+            format_summary(&timings),
+            [
+                "3 ok, 1 none, 0 error, 1 panic",
+                "  Day 02 Part 2: panic",
+                "  Day 03 Part 1: none",
+            ]
+        );
+    }
+}
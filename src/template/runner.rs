@@ -1,56 +1,384 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{self, IsTerminal, Write, stdout};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use crate::template::ANSI_BOLD;
+#[cfg(feature = "dhat-heap")]
+use crate::template::allocations::{Allocation, Allocations};
+use crate::template::aoc_cli::Verdict;
+use crate::template::ocr;
+use crate::template::params::{self, RawParams, Solver};
+use crate::template::readme_stars;
+use crate::template::submissions::{Submission, Submissions, UNKNOWN_ANSWER};
+use crate::template::timings::Stats;
+use crate::template::viz::VizOutput;
 use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Read the parameters of the real input, exiting if they cannot be read.
+pub fn read_params(day: Day) -> RawParams {
+    params::read_input_params(day).unwrap_or_else(|e| {
+        eprintln!("Failed to read puzzle parameters: {e}");
+        process::exit(1);
+    })
+}
+
+/// Part to minimize, passed by the `minimize` command as `--minimize 2`.
+pub fn minimize_args() -> Option<u8> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--minimize")?;
+
+    match args.get(index + 1).map(|part| part.parse()) {
+        Some(Ok(part @ 1..=2)) => Some(part),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --minimize 2");
+            process::exit(1);
+        }
+    }
+}
+
+/// Sizes and seed of generated inputs, passed by the `scale` command as `--scale 250,500 --seed 1`.
+pub struct ScaleArgs {
+    pub sizes: Vec<usize>,
+    pub seed: u64,
+}
+
+/// Read the scale arguments, if the solution is run to benchmark generated inputs.
+pub fn scale_args() -> Option<ScaleArgs> {
+    let args: Vec<String> = env::args().collect();
+    let value = |flag: &str| {
+        let index = args.iter().position(|x| x == flag)?;
+        args.get(index + 1)
+    };
+
+    let sizes = value("--scale")?;
+    let sizes = sizes.split(',').map(str::parse).collect::<Result<_, _>>();
+    let seed = value("--seed").map_or(Ok(1), |s| s.parse());
+
+    match (sizes, seed) {
+        (Ok(sizes), Ok(seed)) => Some(ScaleArgs { sizes, seed }),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --scale 250,500,1000 --seed 1");
+            process::exit(1);
+        }
+    }
+}
+
+/// Where to show visualisation frames, passed by `solve N --viz` as `--viz gif`. Frames are played in the terminal by default.
+pub fn viz_args() -> VizOutput {
+    let args: Vec<String> = env::args().collect();
+    let Some(index) = args.iter().position(|x| x == "--viz") else {
+        return VizOutput::default();
+    };
+
+    args.get(index + 1)
+        .map_or(Ok(VizOutput::default()), |output| output.parse())
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+}
+
+/// Exit when generated inputs were requested from a solution that has no generator.
+pub fn missing_generator(day: Day) -> ! {
+    eprintln!(
+        "Day {day} has no input generator. Add one with `solution!({}, generator = generate)`.",
+        day.into_inner()
+    );
+    process::exit(1);
+}
+
+/// The implementations of one part. Each variant is run and benchmarked on its own,
+/// and their answers are compared once all of them have run.
+pub struct Variants {
+    day: Day,
+    part: u8,
+    names: Vec<&'static str>,
+    results: Vec<Option<String>>,
+    /// Whether a variant panicked or could not be run.
+    failed: bool,
+    size: Option<usize>,
+}
+
+/// Display name of a variant, e.g. "lp" for `part_two_lp`.
+pub fn variant_name(func: &str) -> &str {
+    ["part_one_", "part_two_"]
+        .iter()
+        .find_map(|prefix| func.strip_prefix(prefix))
+        .filter(|name| !name.is_empty())
+        .unwrap_or(func)
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+impl Variants {
+    pub fn new(day: Day, part: u8, names: Vec<&'static str>) -> Self {
+        Variants {
+            day,
+            part,
+            names,
+            results: vec![],
+            failed: false,
+            size: None,
+        }
+    }
+
+    /// Label results with the size of the generated input they were run on, e.g. "Part 1 [n=500]".
+    #[must_use]
+    pub fn with_size(mut self, size: Option<usize>) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Run the next variant, which may take puzzle parameters, see [`Solver`].
+    /// A variant that panics or cannot be run is reported, and the remaining variants still run.
+    pub fn run<M, S: Solver<M>>(&mut self, solver: S, input: &str, params: &RawParams) {
+        let (day, part) = (self.day, self.part);
+
+        let mut label = match self.names.as_slice() {
+            [_] => format!("Part {part}"),
+            names => format!("Part {part} ({})", variant_name(names[self.results.len()])),
+        };
+        if let Some(size) = self.size {
+            label.push_str(&format!(" [n={size}]"));
+        }
+
+        let params = match S::parse_params(params) {
+            Ok(params) => params,
+            Err(e) => {
+                println!(
+                    "{label}: error: {e} Declare it in \"{}\".",
+                    params::get_path_for_input_params(day)
+                );
+                self.failed = true;
+                self.results.push(None);
+                return;
+            }
+        };
+
+        match run_labeled(
+            |input| solver.solve(input, &params),
+            input,
+            day,
+            part,
+            &label,
+        ) {
+            Ok(result) => self.results.push(result.map(|r| r.to_string())),
+            Err(_) => {
+                self.failed = true;
+                self.results.push(None);
+            }
+        }
+
+        #[cfg(feature = "viz")]
+        {
+            let variant =
+                (self.names.len() > 1).then(|| variant_name(self.names[self.results.len() - 1]));
+            crate::template::viz::show(viz_args(), day, part, variant, &label);
+        }
+    }
+
+    /// Check that all variants agree, then submit the answer if requested.
+    /// Disagreeing variants are reported as an error, as the answer cannot be trusted.
+    /// Answers to generated inputs are never submitted.
+    pub fn finish(self) {
+        if self.failed {
+            return;
+        }
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+        let first = self.results.first().cloned().flatten();
 
-    if let Some(result) = result {
+        if self.results.iter().any(|r| *r != first) {
+            let results: Vec<String> = self
+                .names
+                .iter()
+                .zip(&self.results)
+                .map(|(name, result)| {
+                    format!(
+                        "{}: {}",
+                        variant_name(name),
+                        result.as_deref().unwrap_or("✖")
+                    )
+                })
+                .collect();
+            println!(
+                "Part {}: error: variants disagree ({})",
+                self.part,
+                results.join(", ")
+            );
+            return;
+        }
+
+        if let Some(result) = first
+            && self.size.is_none()
+        {
+            submit_result(result, self.day, self.part);
+        }
+    }
+}
+
+/// A panic caught while running a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Where the panic happened, e.g. "src/bin/01.rs:12:5".
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// Run `func`, catching a panic instead of unwinding further. The panic is not printed, but returned.
+/// Builds with `panic = "abort"` still abort, as there is nothing to catch.
+pub fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.get() {
+                CAUGHT.set(Some(Panic {
+                    message: info.payload_as_str().unwrap_or("Box<dyn Any>").to_string(),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_capturing = CAPTURING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CAPTURING.set(was_capturing);
+
+    result.map_err(|_| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: "Box<dyn Any>".into(),
+            location: None,
+        })
+    })
+}
+
+/// Run a part and submit its answer if requested. A panic is reported instead of aborting the remaining parts.
+pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+    if let Ok(Some(result)) = run_labeled(func, input, day, part, &format!("Part {part}")) {
         submit_result(result, day, part);
     }
 }
 
+/// Run and print a part, labeled e.g. "Part 2 (lp)" when it has several variants.
+/// A panic is printed like "Part 2: panicked at src/bin/02.rs:12:5: message".
+fn run_labeled<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    day: Day,
+    part: u8,
+    label: &str,
+) -> Result<Option<T>, Panic> {
+    let run = catch_panic(|| {
+        run_timed(&func, input, day, part, |result| {
+            print_result(result, label, "");
+        })
+    });
+
+    match run {
+        Ok((result, duration, samples, stats)) => {
+            print_result(
+                &result,
+                label,
+                &format_duration(&duration, samples, stats.as_ref()),
+            );
+            Ok(result)
+        }
+        Err(panic) => {
+            print!("\r");
+            println!("{label}: {panic}");
+            Err(panic)
+        }
+    }
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+#[cfg_attr(not(feature = "dhat-heap"), allow(unused_variables))]
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    day: Day,
+    part: u8,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<Stats>) {
+    #[cfg(feature = "dhat-heap")]
+    let heap_stats;
+
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+
+        #[cfg(feature = "dhat-heap")]
+        {
+            heap_stats = dhat::HeapStats::get();
+        }
+
+        result
     };
     let base_time = timer.elapsed();
 
+    #[cfg(feature = "dhat-heap")]
+    record_allocation(day, part, &heap_stats);
+
     hook(&result);
 
     let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+        let (duration, samples, stats) = bench(func, input, &base_time);
+        (duration, samples, Some(stats))
     } else {
-        (base_time, 1)
+        (base_time, 1, None)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, run.2)
+}
+
+/// Store the heap usage of a part, so that it can be shown in the report.
+#[cfg(feature = "dhat-heap")]
+fn record_allocation(day: Day, part: u8, stats: &dhat::HeapStats) {
+    let mut allocations = Allocations::read_from_file();
+    allocations.insert(Allocation {
+        day,
+        part,
+        total_bytes: stats.total_bytes,
+        total_blocks: stats.total_blocks,
+        max_bytes: stats.max_bytes as u64,
+    });
+
+    if let Err(e) = allocations.store_file() {
+        eprintln!("Failed to store allocations: {e}");
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128, Stats) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -71,6 +399,7 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         #[allow(clippy::cast_possible_truncation)]
         Duration::from_nanos(average_duration(&timers) as u64),
         bench_iterations,
+        sample_stats(&timers),
     )
 }
 
@@ -82,11 +411,32 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+#[allow(clippy::cast_precision_loss)]
+fn sample_stats(numbers: &[Duration]) -> Stats {
+    let nanos: Vec<f64> = numbers.iter().map(|d| d.as_nanos() as f64).collect();
+    let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+    let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+    Stats {
+        min_nanos: nanos.iter().copied().fold(f64::INFINITY, f64::min),
+        max_nanos: nanos.iter().copied().fold(0.0, f64::max),
+        stddev_nanos: variance.sqrt(),
+    }
+}
+
+fn format_duration(duration: &Duration, samples: u128, stats: Option<&Stats>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let nanos = |n: f64| Duration::from_nanos(n.round() as u64);
+
+    match stats {
+        _ if samples == 1 => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({duration:.1?} @ {samples} samples, σ {:.1?}, min {:.1?}, max {:.1?})",
+            nanos(stats.stddev_nanos),
+            nanos(stats.min_nanos),
+            nanos(stats.max_nanos)
+        ),
+        None => format!(" ({duration:.1?} @ {samples} samples)"),
     }
 }
 
@@ -103,6 +453,9 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     print!("\r");
                     println!("{str}");
                     println!("{result}");
+                    if let Some(letters) = ocr::recognize(&result.to_string()) {
+                        println!("Recognized letters: {ANSI_BOLD}{letters}{ANSI_RESET}");
+                    }
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
@@ -132,7 +485,7 @@ fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<Verdict, aoc_cli::AocCommandError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -162,6 +515,105 @@ fn submit_result<T: Display>(
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    // answers drawn in block letters are submitted as the letters.
+    let mut answer = result.to_string();
+    if answer.contains('\n') {
+        let Some(letters) = ocr::recognize(&answer) else {
+            eprintln!(
+                "The answer of part {part} spans several lines, but its letters could not be recognized."
+            );
+            process::exit(1);
+        };
+        answer = letters;
+    }
+
+    loop {
+        println!("Submitting result via aoc-cli...");
+        let verdict = aoc_cli::submit(day, part, &answer);
+
+        match &verdict {
+            Ok(Verdict::RateLimited(wait)) => {
+                let question = format!(
+                    "Submitting too soon. Wait {} and submit again?",
+                    aoc_cli::format_wait(*wait)
+                );
+                if confirm(&question) {
+                    wait_for(*wait);
+                    continue;
+                }
+            }
+            // the answer was not judged, but the star was earned before, e.g. before the log existed.
+            Ok(Verdict::AlreadySolved) => {
+                if !Submissions::read_from_file().has_star(day, part) {
+                    record_submission(day, part, UNKNOWN_ANSWER.into(), true);
+                }
+            }
+            Ok(verdict) => {
+                println!("Verdict: {ANSI_BOLD}{verdict}{ANSI_RESET}");
+                record_submission(day, part, answer, verdict.is_correct());
+
+                if verdict.is_correct() && part == 1 {
+                    // part two only appears in the puzzle once part one is solved.
+                    if let Err(e) = aoc_cli::download_puzzle(day) {
+                        eprintln!("Failed to download part two of the puzzle: {e}");
+                    }
+                }
+            }
+            Err(e) => eprintln!("Failed to submit: {e}"),
+        }
+
+        return Some(verdict);
+    }
+}
+
+/// Ask a yes/no question, which defaults to yes. Without an interactive terminal, the answer is no.
+fn confirm(question: &str) -> bool {
+    if !io::stdin().is_terminal() {
+        println!("{question} Not waiting, as no terminal is attached.");
+        return false;
+    }
+
+    print!("{question} [Y/n] ");
+    let _ = stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "" | "y" | "yes")
+}
+
+/// Wait out a rate limit, counting down the remaining time.
+fn wait_for(wait: Duration) {
+    // the server counts in whole seconds, so wait a little longer.
+    let end = Instant::now() + wait + Duration::from_secs(1);
+    let mut stdout = stdout();
+
+    while let Some(left) = end.checked_duration_since(Instant::now()) {
+        print!("\rWaiting {}...   ", aoc_cli::format_wait(left));
+        let _ = stdout.flush();
+        thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    println!();
+}
+
+/// Append a submission to the log and update the stars in the readme when it was accepted.
+fn record_submission(day: Day, part: u8, answer: String, correct: bool) {
+    let mut submissions = Submissions::read_from_file();
+    submissions.data.push(Submission {
+        day,
+        part,
+        answer,
+        correct,
+    });
+
+    if let Err(e) = submissions.store_file() {
+        eprintln!("Failed to store submission: {e}");
+        return;
+    }
+
+    if correct {
+        match readme_stars::update(&submissions) {
+            Ok(()) => println!("Updated stars in readme."),
+            Err(_) => eprintln!("Failed to update stars in readme."),
+        }
+    }
 }
@@ -0,0 +1,403 @@
+/// Module that fits the benchmarks of `scale` to complexity classes, and renders them as a table and a log-log chart.
+use std::{fmt::Display, time::Duration};
+
+use crate::template::run_multi::child_commands::parse_time;
+
+/// Input sizes benchmarked when none are given.
+pub const DEFAULT_SIZES: [usize; 5] = [250, 500, 1000, 2000, 4000];
+
+/// The benchmark of one part (variant) on a generated input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub label: String,
+    pub size: usize,
+    pub nanos: f64,
+}
+
+/// Parse a line printed by a solution with generated inputs, e.g. "Part 2 (lp) [n=500]: 33 (2.0ms @ 10 samples)".
+pub fn parse_sample(line: &str) -> Option<Sample> {
+    let (label, _) = line.split_once(':')?;
+    let (label, size) = label.trim().strip_suffix(']')?.rsplit_once(" [n=")?;
+
+    Some(Sample {
+        label: label.to_string(),
+        size: size.parse().ok()?,
+        nanos: parse_time(line)?.nanos,
+    })
+}
+
+/// All samples of one part (variant), in order of increasing size.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    pub label: String,
+    pub points: Vec<(usize, f64)>,
+}
+
+pub fn group(samples: &[Sample]) -> Vec<Series> {
+    let mut series: Vec<Series> = vec![];
+
+    for sample in samples {
+        let point = (sample.size, sample.nanos);
+        match series.iter_mut().find(|s| s.label == sample.label) {
+            Some(s) => s.points.push(point),
+            None => series.push(Series {
+                label: sample.label.clone(),
+                points: vec![point],
+            }),
+        }
+    }
+
+    for s in &mut series {
+        s.points.sort_by_key(|(size, _)| *size);
+    }
+
+    series
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Complexity {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    QuadraticLogarithmic,
+    Cubic,
+}
+
+impl Complexity {
+    const ALL: [Complexity; 7] = [
+        Complexity::Constant,
+        Complexity::Logarithmic,
+        Complexity::Linear,
+        Complexity::Linearithmic,
+        Complexity::Quadratic,
+        Complexity::QuadraticLogarithmic,
+        Complexity::Cubic,
+    ];
+
+    /// Natural logarithm of the growth function at `n`.
+    fn ln_growth(self, n: f64) -> f64 {
+        let ln_n = n.max(2.0).ln();
+        match self {
+            Complexity::Constant => 0.0,
+            Complexity::Logarithmic => ln_n.ln(),
+            Complexity::Linear => ln_n,
+            Complexity::Linearithmic => ln_n + ln_n.ln(),
+            Complexity::Quadratic => 2.0 * ln_n,
+            Complexity::QuadraticLogarithmic => 2.0 * ln_n + ln_n.ln(),
+            Complexity::Cubic => 3.0 * ln_n,
+        }
+    }
+}
+
+impl Display for Complexity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Complexity::Constant => "O(1)",
+            Complexity::Logarithmic => "O(log n)",
+            Complexity::Linear => "O(n)",
+            Complexity::Linearithmic => "O(n log n)",
+            Complexity::Quadratic => "O(n²)",
+            Complexity::QuadraticLogarithmic => "O(n² log n)",
+            Complexity::Cubic => "O(n³)",
+        };
+        f.pad(s)
+    }
+}
+
+/// An empirical complexity estimate: the slope of the log-log plot, and the closest complexity class.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fit {
+    pub exponent: f64,
+    pub complexity: Complexity,
+}
+
+/// Fit `time ≈ c · nᵏ` by least squares on the log-log points, and pick the class `c · f(n)` with the smallest residual.
+/// At least two distinct sizes are needed.
+#[allow(clippy::cast_precision_loss)]
+pub fn fit(points: &[(usize, f64)]) -> Option<Fit> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .filter(|(_, nanos)| *nanos > 0.0)
+        .map(|(size, nanos)| ((*size as f64).ln(), nanos.ln()))
+        .collect();
+
+    if logs.len() < 2 {
+        return None;
+    }
+
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let var_x: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let cov: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+
+    if var_x == 0.0 {
+        return None;
+    }
+
+    let residual = |complexity: Complexity| {
+        let offsets: Vec<f64> = points
+            .iter()
+            .filter(|(_, nanos)| *nanos > 0.0)
+            .map(|(size, nanos)| nanos.ln() - complexity.ln_growth(*size as f64))
+            .collect();
+        let mean = offsets.iter().sum::<f64>() / count;
+        offsets.iter().map(|o| (o - mean).powi(2)).sum::<f64>()
+    };
+
+    let complexity = Complexity::ALL
+        .into_iter()
+        .min_by(|a, b| residual(*a).total_cmp(&residual(*b)))?;
+
+    Some(Fit {
+        exponent: cov / var_x,
+        complexity,
+    })
+}
+
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Format the timings of every series per size as an aligned table, followed by their fit.
+pub fn format_table(series: &[Series]) -> Vec<String> {
+    let mut sizes: Vec<usize> = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|(size, _)| *size))
+        .collect();
+    sizes.sort_unstable();
+    sizes.dedup();
+
+    let width = series
+        .iter()
+        .map(|s| s.label.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut header = format!("{:<width$}", "Part");
+    for size in &sizes {
+        header.push_str(&format!(" {:>10}", format!("n={size}")));
+    }
+    header.push_str(&format!(" {:>8}  {}", "exponent", "estimate"));
+
+    let mut lines = vec![header];
+
+    for s in series {
+        let mut line = format!("{:<width$}", s.label);
+        for size in &sizes {
+            let nanos = s.points.iter().find(|(n, _)| n == size).map(|(_, t)| *t);
+            line.push_str(&format!(" {:>10}", nanos.map_or("-".into(), format_nanos)));
+        }
+        match fit(&s.points) {
+            Some(fit) => line.push_str(&format!(" {:>8.2}  {}", fit.exponent, fit.complexity)),
+            None => line.push_str(&format!(" {:>8}  -", "-")),
+        }
+        lines.push(line.trim_end().to_string());
+    }
+
+    lines
+}
+
+const MARKERS: [char; 6] = ['*', '+', 'o', 'x', '#', '@'];
+
+/// Render all series as a log-log plot of `width` by `height` characters, followed by a legend.
+/// Points are connected with dots, so that the slope of each series is visible.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::needless_range_loop
+)]
+pub fn render_chart(series: &[Series], width: usize, height: usize) -> Vec<String> {
+    let points = || {
+        series
+            .iter()
+            .flat_map(|s| s.points.iter())
+            .filter(|(_, nanos)| *nanos > 0.0)
+    };
+
+    let (Some(min_size), Some(max_size)) = (
+        points().map(|(size, _)| *size).min(),
+        points().map(|(size, _)| *size).max(),
+    ) else {
+        return vec![];
+    };
+    let min_nanos = points().map(|(_, t)| *t).fold(f64::INFINITY, f64::min);
+    let max_nanos = points().map(|(_, t)| *t).fold(0.0, f64::max);
+
+    let scale = |value: f64, min: f64, max: f64, cells: usize| {
+        let (value, min, max) = (value.ln(), min.ln(), max.ln());
+        if max > min {
+            ((value - min) / (max - min) * (cells - 1) as f64).round() as usize
+        } else {
+            0
+        }
+    };
+    let column = |size: f64| scale(size, min_size as f64, max_size as f64, width);
+    let row = |nanos: f64| height - 1 - scale(nanos, min_nanos, max_nanos, height);
+
+    let mut grid = vec![vec![' '; width]; height];
+
+    for (s, marker) in series.iter().zip(MARKERS.iter().cycle()) {
+        let points: Vec<(f64, f64)> = s
+            .points
+            .iter()
+            .filter(|(_, nanos)| *nanos > 0.0)
+            .map(|(size, nanos)| (*size as f64, *nanos))
+            .collect();
+
+        // interpolate between neighbouring points, linearly in log-log space.
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            let (c0, c1) = (column(x0), column(x1));
+            for c in c0 + 1..c1 {
+                let t = (c - c0) as f64 / (c1 - c0) as f64;
+                let nanos = (y0.ln() + t * (y1.ln() - y0.ln())).exp();
+                let cell = &mut grid[row(nanos)][c];
+                if *cell == ' ' {
+                    *cell = '·';
+                }
+            }
+        }
+
+        for (size, nanos) in points {
+            grid[row(nanos)][column(size)] = *marker;
+        }
+    }
+
+    let top = format_nanos(max_nanos);
+    let bottom = format_nanos(min_nanos);
+    let margin = top.chars().count().max(bottom.chars().count());
+
+    let mut lines: Vec<String> = grid
+        .into_iter()
+        .enumerate()
+        .map(|(i, cells)| {
+            let axis_label = match i {
+                0 => top.as_str(),
+                i if i == height - 1 => bottom.as_str(),
+                _ => "",
+            };
+            let cells: String = cells.into_iter().collect();
+            format!("{axis_label:>margin$} │{}", cells.trim_end())
+        })
+        .collect();
+
+    lines.push(format!("{:>margin$} └{}", "", "─".repeat(width)));
+
+    let (left, right) = (format!("n={min_size}"), format!("n={max_size}"));
+    lines.push(format!(
+        "{:>margin$}  {left}{right:>pad$}",
+        "",
+        pad = width.saturating_sub(left.len())
+    ));
+
+    let legend: Vec<String> = series
+        .iter()
+        .zip(MARKERS.iter().cycle())
+        .map(|(s, marker)| format!("{marker} {}", s.label))
+        .collect();
+    lines.push(format!("{:>margin$}  {}", "", legend.join("   ")));
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Complexity, Sample, Series, fit, format_table, group, parse_sample, render_chart};
+
+    fn series(label: &str, f: impl Fn(f64) -> f64) -> Series {
+        Series {
+            label: label.into(),
+            #[allow(clippy::cast_precision_loss)]
+            points: [250, 500, 1000, 2000, 4000]
+                .into_iter()
+                .map(|n| (n, f(n as f64)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_samples() {
+        assert_eq!(
+            parse_sample(
+                "Part 2 (lp) [n=500]: 33 (2.0ms @ 10 samples, σ 1.0µs, min 1.9ms, max 2.1ms)"
+            ),
+            Some(Sample {
+                label: "Part 2 (lp)".into(),
+                size: 500,
+                nanos: 2e6,
+            })
+        );
+        assert_eq!(parse_sample("Part 1: 33 (2.0ms @ 10 samples)"), None);
+        assert_eq!(parse_sample("Part 1 [n=500]: ✖"), None);
+    }
+
+    #[test]
+    fn groups_samples_by_label() {
+        let sample = |label: &str, size, nanos| Sample {
+            label: label.into(),
+            size,
+            nanos,
+        };
+        let series = group(&[
+            sample("Part 1", 500, 2.0),
+            sample("Part 2", 500, 3.0),
+            sample("Part 1", 250, 1.0),
+        ]);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].points, [(250, 1.0), (500, 2.0)]);
+    }
+
+    #[test]
+    fn fits_complexity() {
+        let quadratic = fit(&series("", |n| 3.0 * n * n).points).unwrap();
+        assert!((quadratic.exponent - 2.0).abs() < 1e-9);
+        assert_eq!(quadratic.complexity, Complexity::Quadratic);
+
+        let linearithmic = fit(&series("", |n| 10.0 * n * n.ln()).points).unwrap();
+        assert_eq!(linearithmic.complexity, Complexity::Linearithmic);
+
+        let constant = fit(&series("", |_| 50.0).points).unwrap();
+        assert_eq!(constant.complexity, Complexity::Constant);
+
+        assert_eq!(fit(&[(100, 1.0)]), None);
+    }
+
+    #[test]
+    fn formats_table() {
+        let lines = format_table(&[series("Part 1", |n| n * 1000.0)]);
+        assert_eq!(
+            lines,
+            [
+                "Part        n=250      n=500     n=1000     n=2000     n=4000 exponent  estimate",
+                "Part 1    250.0µs    500.0µs      1.0ms      2.0ms      4.0ms     1.00  O(n)",
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_chart() {
+        let lines = render_chart(
+            &[
+                series("Part 1", |n| n * 1000.0),
+                series("Part 2", |n| n * n),
+            ],
+            20,
+            5,
+        );
+        assert_eq!(lines.len(), 8);
+        assert!(lines[0].starts_with("16.0ms │"));
+        assert!(lines[0].ends_with('+'));
+        assert!(lines[4].starts_with("62.5µs │+"));
+        assert_eq!(lines[6], "        n=250         n=4000");
+        assert_eq!(lines[7], "        * Part 1   + Part 2");
+    }
+}
@@ -0,0 +1,334 @@
+/// Module that implements an offline full-text search across puzzles and solutions.
+///
+/// Documents are collected per day from `data/puzzles/NN.md` and `src/bin/NN.rs`.
+/// Puzzles of other years can be kept in `data/puzzles/YYYY/NN.md`.
+use std::{collections::HashMap, env, fs, path::Path};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, all_days, puzzle::Puzzle};
+
+const ANSI_YELLOW: &str = "\x1b[33m";
+
+const SNIPPET_RADIUS: usize = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentKind {
+    Puzzle,
+    Solution,
+}
+
+/// A single searchable file.
+#[derive(Clone, Debug)]
+pub struct Document {
+    pub year: Option<u16>,
+    pub day: Day,
+    pub kind: DocumentKind,
+    pub title: Option<String>,
+    pub path: String,
+    pub text: String,
+}
+
+/// A ranked search result.
+#[derive(Clone, Debug)]
+pub struct Match<'a> {
+    pub document: &'a Document,
+    pub score: f64,
+    pub snippet: String,
+}
+
+/// Term frequencies of all indexed documents.
+pub struct Index {
+    pub documents: Vec<Document>,
+    terms: Vec<HashMap<String, usize>>,
+}
+
+/// Lowercase a word and strip a plural `s`, so that "splitters" finds "splitter".
+fn normalize(word: &str) -> String {
+    let word = word.to_lowercase();
+    match word.strip_suffix('s') {
+        Some(stem) if stem.len() > 2 && !stem.ends_with('s') => stem.to_string(),
+        _ => word,
+    }
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(normalize)
+}
+
+fn get_current_year() -> Option<u16> {
+    env::var("AOC_YEAR").ok()?.parse().ok()
+}
+
+fn read_document(year: Option<u16>, day: Day, kind: DocumentKind, path: &Path) -> Option<Document> {
+    let text = fs::read_to_string(path).ok()?;
+    let title = match kind {
+        DocumentKind::Puzzle => Puzzle::parse(&text).title,
+        DocumentKind::Solution => None,
+    };
+    Some(Document {
+        year,
+        day,
+        kind,
+        title,
+        path: path.to_string_lossy().to_string(),
+        text,
+    })
+}
+
+/// Collect puzzles and solutions of the current year, and puzzles of archived years.
+pub fn collect_documents() -> Vec<Document> {
+    let current_year = get_current_year();
+    let mut documents = vec![];
+
+    for day in all_days() {
+        let puzzle = Path::new("data/puzzles").join(format!("{day}.md"));
+        let solution = Path::new("src/bin").join(format!("{day}.rs"));
+        documents.extend(read_document(
+            current_year,
+            day,
+            DocumentKind::Puzzle,
+            &puzzle,
+        ));
+        documents.extend(read_document(
+            current_year,
+            day,
+            DocumentKind::Solution,
+            &solution,
+        ));
+    }
+
+    let mut years: Vec<(u16, _)> = fs::read_dir("data/puzzles")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| Some((entry.file_name().to_str()?.parse().ok()?, entry.path())))
+        .collect();
+    years.sort_unstable_by_key(|(year, _)| *year);
+
+    for (year, dir) in years {
+        for day in all_days() {
+            let puzzle = dir.join(format!("{day}.md"));
+            documents.extend(read_document(
+                Some(year),
+                day,
+                DocumentKind::Puzzle,
+                &puzzle,
+            ));
+        }
+    }
+
+    documents
+}
+
+impl Index {
+    pub fn new(documents: Vec<Document>) -> Self {
+        let terms = documents
+            .iter()
+            .map(|document| {
+                let mut counts: HashMap<String, usize> = HashMap::new();
+                let title = document.title.as_deref().unwrap_or_default();
+                for term in tokenize(&document.text).chain(tokenize(title)) {
+                    *counts.entry(term).or_default() += 1;
+                }
+                counts
+            })
+            .collect();
+
+        Index { documents, terms }
+    }
+
+    /// Rank documents by tf-idf. Titles weigh more than text, and solutions less than puzzles.
+    /// Only documents containing every term of the query are returned.
+    pub fn search(&self, query: &str) -> Vec<Match<'_>> {
+        let query: Vec<String> = tokenize(query).collect();
+        if query.is_empty() {
+            return vec![];
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.documents.len() as f64;
+
+        let mut matches: Vec<Match> = self
+            .documents
+            .iter()
+            .zip(&self.terms)
+            .filter(|(_, terms)| query.iter().all(|t| terms.contains_key(t)))
+            .map(|(document, terms)| {
+                let title: Vec<String> =
+                    tokenize(document.title.as_deref().unwrap_or_default()).collect();
+
+                let score: f64 = query
+                    .iter()
+                    .map(|term| {
+                        #[allow(clippy::cast_precision_loss)]
+                        let df = self.terms.iter().filter(|t| t.contains_key(term)).count() as f64;
+                        #[allow(clippy::cast_precision_loss)]
+                        let tf = terms[term] as f64;
+                        let boost = if title.contains(term) { 3.0 } else { 1.0 };
+                        (1.0 + tf.ln()) * (1.0 + (n / df).ln()) * boost
+                    })
+                    .sum();
+
+                let weight = match document.kind {
+                    DocumentKind::Puzzle => 1.0,
+                    DocumentKind::Solution => 0.5,
+                };
+
+                Match {
+                    document,
+                    score: score * weight,
+                    snippet: snippet(&document.text, &query),
+                }
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches
+    }
+}
+
+/// Find the line with the most query terms and highlight them.
+pub fn snippet(text: &str, query: &[String]) -> String {
+    let contains_term = |line: &str| {
+        let tokens: Vec<String> = tokenize(line).collect();
+        query.iter().filter(|t| tokens.contains(t)).count()
+    };
+
+    let Some(line) = text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .max_by_key(|l| contains_term(l))
+    else {
+        return String::new();
+    };
+
+    // center the snippet around the first match.
+    let first = line
+        .char_indices()
+        .position(|(i, _)| {
+            let rest = line[i..].to_lowercase();
+            query.iter().any(|t| rest.starts_with(t.as_str()))
+        })
+        .unwrap_or(0);
+
+    let chars: Vec<char> = line.chars().collect();
+    let start = first.saturating_sub(SNIPPET_RADIUS);
+    let end = (first + SNIPPET_RADIUS).min(chars.len());
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    out.push_str(&highlight(
+        &chars[start..end].iter().collect::<String>(),
+        query,
+    ));
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+fn highlight(text: &str, query: &[String]) -> String {
+    let mut out = String::new();
+    let mut word = String::new();
+
+    let flush = |word: &mut String, out: &mut String| {
+        if query.contains(&normalize(word)) {
+            out.push_str(&format!("{ANSI_BOLD}{ANSI_YELLOW}{word}{ANSI_RESET}"));
+        } else {
+            out.push_str(word);
+        }
+        word.clear();
+    };
+
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut word, &mut out);
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Document, DocumentKind, Index, snippet};
+    use crate::day;
+
+    fn get_mock_index() -> Index {
+        let document = |day, kind, title: Option<&str>, text: &str| Document {
+            year: Some(2025),
+            day,
+            kind,
+            title: title.map(String::from),
+            path: String::new(),
+            text: text.into(),
+        };
+
+        Index::new(vec![
+            document(
+                day!(7),
+                DocumentKind::Puzzle,
+                Some("Laboratories"),
+                "A tachyon beam enters the manifold.\nEach splitter splits the beam.",
+            ),
+            document(
+                day!(7),
+                DocumentKind::Solution,
+                None,
+                "fn count_splits() {}\n// beam",
+            ),
+            document(
+                day!(4),
+                DocumentKind::Puzzle,
+                Some("Printing Department"),
+                "Rolls of paper are everywhere.",
+            ),
+        ])
+    }
+
+    #[test]
+    fn ranks_puzzles_above_solutions() {
+        let index = get_mock_index();
+        let matches = index.search("beam");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].document.kind, DocumentKind::Puzzle);
+        assert_eq!(matches[1].document.kind, DocumentKind::Solution);
+    }
+
+    #[test]
+    fn requires_all_terms() {
+        let index = get_mock_index();
+        assert_eq!(index.search("splitter beam").len(), 1);
+        assert_eq!(index.search("paper beam").len(), 0);
+        assert_eq!(index.search("").len(), 0);
+    }
+
+    #[test]
+    fn searches_titles() {
+        let index = get_mock_index();
+        let matches = index.search("printing");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].document.day, day!(4));
+    }
+
+    #[test]
+    fn builds_snippets() {
+        let s = snippet(
+            "first line\nEach splitter splits the beam.",
+            &["beam".into()],
+        );
+        assert!(s.starts_with("Each splitter splits the "));
+        assert!(s.contains("beam"));
+        assert!(s.ends_with('.'));
+    }
+}
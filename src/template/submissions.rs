@@ -0,0 +1,208 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// Recorded as the answer of parts the server reports as solved before, whose accepted answer is unknown.
+pub const UNKNOWN_ANSWER: &str = "(solved before)";
+
+/// Represents a single answer submitted for a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub correct: bool,
+}
+
+/// Represents the log of all submitted answers.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(SUBMISSIONS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// The accepted answer of a part, if any and known.
+    pub fn accepted(&self, day: Day, part: u8) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.correct && s.answer != UNKNOWN_ANSWER)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Whether a part has earned a star.
+    pub fn has_star(&self, day: Day, part: u8) -> bool {
+        self.data
+            .iter()
+            .any(|s| s.day == day && s.part == part && s.correct)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert("correct".into(), JsonValue::Boolean(value.correct));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .filter(|p| **p == 1.0 || **p == 2.0)
+            .map(|p| if *p == 1.0 { 1 } else { 2 })
+            .ok_or("Expected submission.part to be 1 or 2.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let correct = json
+            .get("correct")
+            .and_then(|v| v.get::<bool>())
+            .ok_or("Expected submission.correct to be a boolean.")?;
+
+        Ok(Submission {
+            day,
+            part,
+            answer: answer.clone(),
+            correct: *correct,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submission, Submissions, UNKNOWN_ANSWER};
+    use crate::day;
+
+    #[test]
+    fn roundtrips_json() {
+        let submissions = Submissions {
+            data: vec![
+                Submission {
+                    day: day!(1),
+                    part: 1,
+                    answer: "3".into(),
+                    correct: false,
+                },
+                Submission {
+                    day: day!(1),
+                    part: 1,
+                    answer: "4".into(),
+                    correct: true,
+                },
+            ],
+        };
+
+        let json = JsonValue::from(submissions.clone()).stringify().unwrap();
+        let parsed = Submissions::try_from(json).unwrap();
+        assert_eq!(parsed.data, submissions.data);
+        assert_eq!(parsed.accepted(day!(1), 1), Some("4"));
+        assert!(!parsed.has_star(day!(1), 2));
+    }
+
+    #[test]
+    fn counts_parts_solved_before_as_stars() {
+        let submissions = Submissions {
+            data: vec![Submission {
+                day: day!(2),
+                part: 1,
+                answer: UNKNOWN_ANSWER.into(),
+                correct: true,
+            }],
+        };
+
+        assert!(submissions.has_star(day!(2), 1));
+        assert_eq!(submissions.accepted(day!(2), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_part() {
+        let json = r#"{ "data": [{ "day": "01", "part": 3, "answer": "1", "correct": true }] }"#;
+        Submissions::try_from(json.to_string()).unwrap();
+    }
+}
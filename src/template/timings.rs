@@ -1,17 +1,95 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::run_multi::child_commands::parse_duration;
+use crate::template::{Day, all_days};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Version 1 had no `version` key and stored parts as display strings like "46.1µs".
+pub const TIMINGS_VERSION: u64 = 2;
+
+/// Spread of the samples of a benchmarked part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min_nanos: f64,
+    pub max_nanos: f64,
+    pub stddev_nanos: f64,
+}
+
+/// Benchmark result of a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Mean duration of a sample.
+    pub nanos: f64,
+    /// Number of samples, `0` for timings migrated from version 1, which did not record it.
+    pub samples: u64,
+    pub stats: Option<Stats>,
+}
+
+/// How running a part ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Ok,
+    /// The part returned `None`.
+    None,
+    /// The part could not be run, e.g. because of missing parameters, or its variants disagree.
+    Error,
+    Panic,
+}
+
+/// How stored part times are combined with a new benchmark run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Take the new time, unless the part did not produce one.
+    #[default]
+    Some,
+    /// Always take the new time, removing parts that did not produce one.
+    Latest,
+    /// Keep the faster of both times.
+    Best,
+}
+
+/// A part whose stored time differs after a merge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: Day,
+    pub part: u8,
+    pub old: Option<PartTiming>,
+    pub new: Option<PartTiming>,
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Build profile of the solution, if benchmarked with `time --profiles`.
+    pub profile: Option<String>,
+    /// All implementations of parts that have several, see [`solution!`](crate::solution).
+    /// `part_1` and `part_2` hold the fastest of them.
+    pub variants: Vec<VariantTiming>,
+    /// Status of both parts, if they were run.
+    pub status: [Option<PartStatus>; 2],
+}
+
+/// Benchmark result of one of several implementations of a part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantTiming {
+    pub part: u8,
+    pub name: String,
+    pub timing: PartTiming,
 }
 
 /// Represents benchmark times for a set of days.
@@ -21,6 +99,58 @@ pub struct Timings {
     pub data: Vec<Timing>,
 }
 
+impl PartTiming {
+    pub fn duration(&self) -> Duration {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Duration::from_nanos(self.nanos.round() as u64)
+    }
+}
+
+impl Display for PartTiming {
+    /// Formats like the runner output, e.g. "46.1µs".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{:.1?}", self.duration()))
+    }
+}
+
+impl Timing {
+    /// Implementations of a part, fastest first.
+    pub fn variants(&self, part: u8) -> Vec<&VariantTiming> {
+        let mut variants: Vec<_> = self.variants.iter().filter(|v| v.part == part).collect();
+        variants.sort_by(|a, b| a.timing.nanos.total_cmp(&b.timing.nanos));
+        variants
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self, part: u8) -> Option<PartStatus> {
+        match part {
+            1 | 2 => self.status[usize::from(part - 1)],
+            _ => None,
+        }
+    }
+
+    /// Duration of a part in nanoseconds, if it has been benchmarked.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|p| p.nanos)
+    }
+
+    /// Combined duration of both parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        self.part_1
+            .iter()
+            .chain(&self.part_2)
+            .map(|p| p.nanos)
+            .sum()
+    }
+}
+
 impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
@@ -30,19 +160,67 @@ impl Timings {
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Files written with an older schema are migrated in place.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+        let Ok(contents) = fs::read_to_string(TIMINGS_FILE_PATH) else {
+            return Timings::default();
+        };
+
+        let parsed = JsonValue::from_str(&contents)
+            .map_err(|_| "not valid JSON file.".to_string())
+            .and_then(|json| Ok((schema_version(&json)?, Timings::try_from(&json)?)));
+
+        match parsed {
+            Ok((version, timings)) => {
+                if version < TIMINGS_VERSION {
+                    match timings.store_file() {
+                        Ok(()) => println!(
+                            "Migrated \"{TIMINGS_FILE_PATH}\" from version {version} to {TIMINGS_VERSION}."
+                        ),
+                        Err(e) => eprintln!("Failed to migrate \"{TIMINGS_FILE_PATH}\": {e}"),
+                    }
+                }
+                timings
+            }
+            Err(e) => {
+                eprintln!("Failed to read \"{TIMINGS_FILE_PATH}\": {e}");
+                Timings::default()
+            }
+        }
     }
 
-    /// Merge two sets of timings, overwriting `self` with `other` if present.
-    pub fn merge(&self, new: &Self) -> Self {
+    /// Merge two sets of timings part by part, combining parts present in both according to `policy`.
+    pub fn merge(&self, new: &Self, policy: MergePolicy) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let merged = match self.data.iter().find(|t| t.day == timing.day) {
+                Some(old) => {
+                    let part_1 = policy.merge_part(old.part_1, timing.part_1);
+                    let part_2 = policy.merge_part(old.part_2, timing.part_2);
+
+                    // the profile follows the run that contributed a part time.
+                    let is_new = (part_1.is_some() && part_1 == timing.part_1)
+                        || (part_2.is_some() && part_2 == timing.part_2);
+                    let profile = if is_new {
+                        &timing.profile
+                    } else {
+                        &old.profile
+                    };
+
+                    Timing {
+                        day: timing.day,
+                        part_1,
+                        part_2,
+                        profile: profile.clone(),
+                        variants: policy.merge_variants(&old.variants, &timing.variants),
+                        // the status is always the latest one known, even when an older time is kept.
+                        status: [0, 1].map(|i| timing.status[i].or(old.status[i])),
+                    }
+                }
+                None => timing.clone(),
+            };
+            data.push(merged);
         }
 
         for timing in &self.data {
@@ -51,13 +229,35 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
+    /// Parts whose time differs between `self` and `other`.
+    pub fn changes(&self, other: &Self) -> Vec<Change> {
+        let find = |timings: &Self, day| timings.data.iter().find(|t| t.day == day).cloned();
+
+        all_days()
+            .flat_map(|day| {
+                let old = find(self, day);
+                let new = find(other, day);
+                (1..=2).filter_map(move |part| {
+                    let old = old.as_ref().and_then(|t| t.part(part)).copied();
+                    let new = new.as_ref().and_then(|t| t.part(part)).copied();
+                    (old != new).then_some(Change {
+                        day,
+                        part,
+                        old,
+                        new,
+                    })
+                })
+            })
+            .collect()
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -67,12 +267,412 @@ impl Timings {
     }
 }
 
+impl MergePolicy {
+    fn merge_part(self, old: Option<PartTiming>, new: Option<PartTiming>) -> Option<PartTiming> {
+        match (self, old, new) {
+            (MergePolicy::Latest, _, new) => new,
+            (MergePolicy::Some | MergePolicy::Best, old, None) => old,
+            (MergePolicy::Best, Some(old), Some(new)) if old.nanos <= new.nanos => Some(old),
+            (_, _, new) => new,
+        }
+    }
+
+    /// Merge the variants of a day one by one, keyed by part and name, like the part times.
+    fn merge_variants(self, old: &[VariantTiming], new: &[VariantTiming]) -> Vec<VariantTiming> {
+        let find = |variants: &[VariantTiming], part, name: &str| {
+            variants
+                .iter()
+                .find(|v| v.part == part && v.name == name)
+                .map(|v| v.timing)
+        };
+
+        let mut merged: Vec<VariantTiming> = vec![];
+        for variant in new.iter().chain(old) {
+            if merged
+                .iter()
+                .any(|v| v.part == variant.part && v.name == variant.name)
+            {
+                continue;
+            }
+
+            let timing = self.merge_part(
+                find(old, variant.part, &variant.name),
+                find(new, variant.part, &variant.name),
+            );
+            if let Some(timing) = timing {
+                merged.push(VariantTiming {
+                    part: variant.part,
+                    name: variant.name.clone(),
+                    timing,
+                });
+            }
+        }
+
+        merged
+    }
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            PartStatus::Ok => "ok",
+            PartStatus::None => "none",
+            PartStatus::Error => "error",
+            PartStatus::Panic => "panic",
+        };
+        f.pad(s)
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(PartStatus::Ok),
+            "none" => Ok(PartStatus::None),
+            "error" => Ok(PartStatus::Error),
+            "panic" => Ok(PartStatus::Panic),
+            _ => Err(format!(
+                "unknown status \"{s}\", expected one of: ok, none, error, panic."
+            )),
+        }
+    }
+}
+
+impl FromStr for MergePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "some" => Ok(MergePolicy::Some),
+            "latest" => Ok(MergePolicy::Latest),
+            "best" => Ok(MergePolicy::Best),
+            _ => Err(format!(
+                "unknown merge policy \"{s}\", expected one of: some, latest, best."
+            )),
+        }
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} Part {}: ", self.day, self.part)?;
+        match (self.old, self.new) {
+            (None, Some(new)) => write!(f, "added {new}"),
+            (Some(old), None) => write!(f, "removed {old}"),
+            (Some(old), Some(new)) => {
+                let delta = (new.nanos - old.nanos) / old.nanos * 100.0;
+                write!(f, "{old} -> {new} ({delta:+.1}%)")
+            }
+            (None, None) => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// Represents a single benchmark run, recorded at a point in time.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub timings: Timings,
+}
+
+/// Represents all stored benchmark runs, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct TimingsHistory {
+    pub runs: Vec<HistoryEntry>,
+}
+
+impl TimingsHistory {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(HISTORY_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(TimingsHistory::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Append a run, timestamped with the current time.
+    pub fn push(&mut self, timings: Timings) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.runs.push(HistoryEntry { timestamp, timings });
+    }
+
+    /// All recorded timings of a day, oldest first.
+    pub fn for_day(&self, day: Day) -> impl Iterator<Item = (u64, &Timing)> {
+        self.runs.iter().filter_map(move |run| {
+            run.timings
+                .data
+                .iter()
+                .find(|t| t.day == day)
+                .map(|t| (run.timestamp, t))
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
+/// Schema errors name the offending path, e.g. "`data[2].part_1.nanos`: expected a number, found a string."
+fn describe(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Number(_) => "a number",
+        JsonValue::Boolean(_) => "a boolean",
+        JsonValue::String(_) => "a string",
+        JsonValue::Null => "null",
+        JsonValue::Array(_) => "an array",
+        JsonValue::Object(_) => "an object",
+    }
+}
+
+fn expect_object<'a>(
+    value: &'a JsonValue,
+    path: &str,
+) -> Result<&'a HashMap<String, JsonValue>, String> {
+    value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or_else(|| format!("`{path}`: expected an object, found {}.", describe(value)))
+}
+
+fn expect_field<'a>(
+    object: &'a HashMap<String, JsonValue>,
+    key: &str,
+    path: &str,
+) -> Result<&'a JsonValue, String> {
+    object
+        .get(key)
+        .ok_or_else(|| format!("`{path}`: missing key `{key}`."))
+}
+
+fn expect_number(value: &JsonValue, path: &str) -> Result<f64, String> {
+    match value.get::<f64>() {
+        Some(n) if n.is_finite() && *n >= 0.0 => Ok(*n),
+        Some(n) => Err(format!(
+            "`{path}`: expected a non-negative number, found {n}."
+        )),
+        None => Err(format!(
+            "`{path}`: expected a number, found {}.",
+            describe(value)
+        )),
+    }
+}
+
+fn expect_integer(value: &JsonValue, path: &str) -> Result<u64, String> {
+    let n = expect_number(value, path)?;
+    if n.fract() != 0.0 {
+        return Err(format!("`{path}`: expected an integer, found {n}."));
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(n as u64)
+}
+
+/// Version of a timings document. Documents without a `version` key are version 1.
+fn schema_version(json: &JsonValue) -> Result<u64, String> {
+    let object = expect_object(json, "$")?;
+    let version = match object.get("version") {
+        Some(v) => expect_integer(v, "version")?,
+        None => 1,
+    };
+
+    if version == 0 || version > TIMINGS_VERSION {
+        return Err(format!(
+            "`version`: unsupported version {version}, expected 1 to {TIMINGS_VERSION}."
+        ));
+    }
+
+    Ok(version)
+}
+
+fn parse_day(object: &HashMap<String, JsonValue>, path: &str) -> Result<Day, String> {
+    let value = expect_field(object, "day", path)?;
+    let day = value.get::<String>().ok_or_else(|| {
+        format!(
+            "`{path}.day`: expected a string, found {}.",
+            describe(value)
+        )
+    })?;
+    Day::from_str(day).map_err(|_| format!("`{path}.day`: \"{day}\" is not a valid day."))
+}
+
+fn parse_stats(value: &JsonValue, path: &str) -> Result<Stats, String> {
+    let object = expect_object(value, path)?;
+    let number =
+        |key: &str| expect_number(expect_field(object, key, path)?, &format!("{path}.{key}"));
+
+    Ok(Stats {
+        min_nanos: number("min_nanos")?,
+        max_nanos: number("max_nanos")?,
+        stddev_nanos: number("stddev_nanos")?,
+    })
+}
+
+fn parse_part(value: &JsonValue, path: &str, version: u64) -> Result<Option<PartTiming>, String> {
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    if version == 1 {
+        let s = value.get::<String>().ok_or_else(|| {
+            format!(
+                "`{path}`: expected null or a string, found {}.",
+                describe(value)
+            )
+        })?;
+        let nanos =
+            parse_duration(s).ok_or_else(|| format!("`{path}`: \"{s}\" is not a duration."))?;
+        return Ok(Some(PartTiming {
+            nanos,
+            samples: 0,
+            stats: None,
+        }));
+    }
+
+    let object = value.get::<HashMap<String, JsonValue>>().ok_or_else(|| {
+        format!(
+            "`{path}`: expected null or an object, found {}.",
+            describe(value)
+        )
+    })?;
+
+    let nanos = expect_number(
+        expect_field(object, "nanos", path)?,
+        &format!("{path}.nanos"),
+    )?;
+    let samples = expect_integer(
+        expect_field(object, "samples", path)?,
+        &format!("{path}.samples"),
+    )?;
+    let stats = match object.get("stats") {
+        None | Some(JsonValue::Null) => None,
+        Some(stats) => Some(parse_stats(stats, &format!("{path}.stats"))?),
+    };
+
+    Ok(Some(PartTiming {
+        nanos,
+        samples,
+        stats,
+    }))
+}
+
+fn parse_variants(value: &JsonValue, path: &str) -> Result<Vec<VariantTiming>, String> {
+    let variants = value
+        .get::<Vec<JsonValue>>()
+        .ok_or_else(|| format!("`{path}`: expected an array, found {}.", describe(value)))?;
+
+    variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            let path = format!("{path}[{i}]");
+            let object = expect_object(variant, &path)?;
+
+            let part = expect_integer(
+                expect_field(object, "part", &path)?,
+                &format!("{path}.part"),
+            )?;
+            if part != 1 && part != 2 {
+                return Err(format!("`{path}.part`: expected 1 or 2, found {part}."));
+            }
+
+            let name = expect_field(object, "name", &path)?;
+            let name = name.get::<String>().ok_or_else(|| {
+                format!(
+                    "`{path}.name`: expected a string, found {}.",
+                    describe(name)
+                )
+            })?;
+
+            let timing = parse_part(variant, &path, TIMINGS_VERSION)?
+                .ok_or_else(|| format!("`{path}`: expected an object, found null."))?;
+
+            Ok(VariantTiming {
+                #[allow(clippy::cast_possible_truncation)]
+                part: part as u8,
+                name: name.clone(),
+                timing,
+            })
+        })
+        .collect()
+}
+
+fn parse_status(value: &JsonValue, path: &str) -> Result<[Option<PartStatus>; 2], String> {
+    let statuses = match value.get::<Vec<JsonValue>>() {
+        Some(statuses) if statuses.len() == 2 => statuses,
+        _ => {
+            return Err(format!(
+                "`{path}`: expected an array of two statuses, found {}.",
+                describe(value)
+            ));
+        }
+    };
+
+    let status = |i: usize| match &statuses[i] {
+        JsonValue::Null => Ok(None),
+        JsonValue::String(s) => s
+            .parse()
+            .map(Some)
+            .map_err(|e| format!("`{path}[{i}]`: {e}")),
+        value => Err(format!(
+            "`{path}[{i}]`: expected null or a string, found {}.",
+            describe(value)
+        )),
+    };
+
+    Ok([status(0)?, status(1)?])
+}
+
+fn parse_timing(value: &JsonValue, path: &str, version: u64) -> Result<Timing, String> {
+    let object = expect_object(value, path)?;
+
+    Ok(Timing {
+        day: parse_day(object, path)?,
+        part_1: parse_part(
+            expect_field(object, "part_1", path)?,
+            &format!("{path}.part_1"),
+            version,
+        )?,
+        part_2: parse_part(
+            expect_field(object, "part_2", path)?,
+            &format!("{path}.part_2"),
+            version,
+        )?,
+        variants: match object.get("variants") {
+            None | Some(JsonValue::Null) => vec![],
+            Some(value) => parse_variants(value, &format!("{path}.variants"))?,
+        },
+        status: match object.get("status") {
+            None | Some(JsonValue::Null) => [None; 2],
+            Some(value) => parse_status(value, &format!("{path}.status"))?,
+        },
+        profile: match object.get("profile") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::String(profile)) => Some(profile.clone()),
+            Some(value) => {
+                return Err(format!(
+                    "`{path}.profile`: expected null or a string, found {}.",
+                    describe(value)
+                ));
+            }
+        },
+    })
+}
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("version".into(), JsonValue::Number(TIMINGS_VERSION as f64));
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -82,55 +682,188 @@ impl From<Timings> for JsonValue {
     }
 }
 
-impl TryFrom<String> for Timings {
+impl TryFrom<&JsonValue> for Timings {
     type Error = String;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
+        let version = schema_version(json)?;
+        let object = expect_object(json, "$")?;
 
-        let json_data = json
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
-            .get("data")
-            .ok_or("expected JSON document to have key `data`.")?
+        let json_data = expect_field(object, "data", "$")?;
+        let json_data = json_data
             .get::<Vec<JsonValue>>()
-            .ok_or("expected `json.data` to be an array.")?;
+            .ok_or_else(|| format!("`data`: expected an array, found {}.", describe(json_data)))?;
 
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .enumerate()
+                .map(|(i, timing)| parse_timing(timing, &format!("data[{i}]"), version))
                 .collect::<Result<_, _>>()?,
         })
     }
 }
 
+impl TryFrom<String> for Timings {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<TimingsHistory> for JsonValue {
+    fn from(value: TimingsHistory) -> Self {
+        let runs = value
+            .runs
+            .into_iter()
+            .map(|run| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                #[allow(clippy::cast_precision_loss)]
+                map.insert("timestamp".into(), JsonValue::Number(run.timestamp as f64));
+                map.insert("timings".into(), JsonValue::from(run.timings));
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("runs".into(), JsonValue::Array(runs));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for TimingsHistory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_runs = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("runs")
+            .ok_or("expected JSON document to have key `runs`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.runs` to be an array.")?;
+
+        let runs = json_runs
+            .iter()
+            .map(|run| {
+                let run = run
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected run to be a JSON object.")?;
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let timestamp = run
+                    .get("timestamp")
+                    .and_then(|v| v.get::<f64>())
+                    .map(|t| *t as u64)
+                    .ok_or("Expected run.timestamp to be a number.")?;
+
+                let timings = run
+                    .get("timings")
+                    .ok_or("Expected run to have key `timings`.")
+                    .map_err(String::from)
+                    .and_then(Timings::try_from)?;
+
+                Ok(HistoryEntry { timestamp, timings })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(TimingsHistory { runs })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("min_nanos".into(), JsonValue::Number(value.min_nanos));
+        map.insert("max_nanos".into(), JsonValue::Number(value.max_nanos));
+        map.insert("stddev_nanos".into(), JsonValue::Number(value.stddev_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&VariantTiming> for JsonValue {
+    fn from(value: &VariantTiming) -> Self {
+        let mut json = JsonValue::from(&value.timing);
+        if let JsonValue::Object(map) = &mut json {
+            map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+            map.insert("name".into(), JsonValue::String(value.name.clone()));
+        }
+        json
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_1
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
-
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_2
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        if !value.variants.is_empty() {
+            map.insert(
+                "variants".into(),
+                JsonValue::Array(value.variants.iter().map(JsonValue::from).collect()),
+            );
+        }
+        if value.status.iter().any(Option::is_some) {
+            map.insert(
+                "status".into(),
+                JsonValue::Array(
+                    value
+                        .status
+                        .iter()
+                        .map(|s| s.map_or(JsonValue::Null, |s| JsonValue::String(s.to_string())))
+                        .collect(),
+                ),
+            );
+        }
+        map.insert(
+            "profile".into(),
+            value
+                .profile
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
         );
 
         JsonValue::Object(map)
@@ -140,38 +873,9 @@ impl From<&Timing> for JsonValue {
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
+    /// Parse a single timing of the current schema version.
     fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
-        let json = value
-            .get::<HashMap<String, JsonValue>>()
-            .ok_or("Expected timing to be a JSON object.")?;
-
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
-
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
-
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
-
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
-        })
+        parse_timing(value, "timing", TIMINGS_VERSION)
     }
 }
 
@@ -181,28 +885,42 @@ impl TryFrom<&JsonValue> for Timing {
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    pub(super) fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1: part(10e6),
+                    part_2: part(20e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1: part(30e6),
+                    part_2: part(40e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    part_1: part(40e6),
                     part_2: None,
-                    total_nanos: 4e+10,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 },
             ],
         }
@@ -213,14 +931,27 @@ mod tests {
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10, "stats": { "min_nanos": 900000, "max_nanos": 1200000, "stddev_nanos": 5000 } }, "part_2": null }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 10);
+            assert_eq!(part_1.stats.unwrap().max_nanos, 1_200_000_f64);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_version_1() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.unwrap();
+            assert_eq!(part_1.nanos, 1_500_000_f64);
+            assert_eq!(part_1.samples, 0);
+            assert_eq!(part_1.to_string(), "1.5ms");
         }
 
         #[test]
@@ -243,10 +974,43 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        fn reports_schema_errors() {
+            let error = |json: &str| Timings::try_from(json.to_string()).unwrap_err();
+
+            assert_eq!(
+                error(
+                    r#"{ "version": 2, "data": [{ "day": "01", "part_1": null, "part_2": null }, { "day": "02", "part_1": { "nanos": "1ms", "samples": 1 }, "part_2": null }] }"#
+                ),
+                "`data[1].part_1.nanos`: expected a number, found a string."
+            );
+            assert_eq!(
+                error(
+                    r#"{ "version": 2, "data": [{ "day": "26", "part_1": null, "part_2": null }] }"#
+                ),
+                "`data[0].day`: \"26\" is not a valid day."
+            );
+            assert_eq!(
+                error(r#"{ "version": 2, "data": [{ "day": "01", "part_1": null }] }"#),
+                "`data[0]`: missing key `part_2`."
+            );
+            assert_eq!(
+                error(
+                    r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1, "samples": 1.5 }, "part_2": null }] }"#
+                ),
+                "`data[0].part_1.samples`: expected an integer, found 1.5."
+            );
+            assert_eq!(
+                error(r#"{ "version": 3, "data": [] }"#),
+                "`version`: unsupported version 3, expected 1 to 2."
+            );
+        }
     }
 
     mod serialization {
-        use super::get_mock_timings;
+        use super::{get_mock_timings, part};
+        use crate::template::timings::{PartStatus, PartTiming, Stats, Timings, VariantTiming};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,9 +1030,52 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = Some(PartTiming {
+                nanos: 46_100.0,
+                samples: 100,
+                stats: Some(Stats {
+                    min_nanos: 40_000.0,
+                    max_nanos: 60_000.0,
+                    stddev_nanos: 2_300.0,
+                }),
+            });
+
+            timings.data[1].profile = Some("lto".into());
+            timings.data[1].variants = vec![VariantTiming {
+                part: 2,
+                name: "lp".into(),
+                timing: part(40e6).unwrap(),
+            }];
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert!(json.contains(r#""version":2"#));
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[1].profile.as_deref(), Some("lto"));
+            assert_eq!(parsed.data[1].variants, timings.data[1].variants);
+            assert!(parsed.data[0].variants.is_empty());
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
+        #[test]
+        fn roundtrips_statuses() {
+            let mut timings = get_mock_timings();
+            timings.data[2].status = [Some(PartStatus::Ok), Some(PartStatus::Panic)];
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            assert!(json.contains(r#""status":["ok","panic"]"#));
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].status, [None; 2]);
+            assert_eq!(parsed.data[2].status(2), Some(PartStatus::Panic));
+        }
     }
 
     mod is_day_complete {
+        use super::part;
         use crate::{
             day,
             template::timings::{Timing, Timings},
@@ -279,13 +1086,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    part_1: part(1e6),
+                    part_2: part(2e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +1102,15 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    part_1: part(1e6),
                     part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -309,21 +1120,23 @@ mod tests {
                     day: day!(1),
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0.0,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{MergePolicy, PartStatus, Timing, Timings, VariantTiming},
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, part};
 
         #[test]
         fn handles_disjunct_timings() {
@@ -333,10 +1146,12 @@ mod tests {
                     day: day!(3),
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 }],
             };
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest);
             assert_eq!(merged.data.len(), 4);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
@@ -353,23 +1168,198 @@ mod tests {
                     day: day!(2),
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
                 }],
             };
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_parts_without_new_times() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: part(5e6),
+                    part_2: None,
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                }],
+            };
+            let merged = timings.merge(&other, MergePolicy::Some);
+            assert_eq!(merged.data[0].part_nanos(1), Some(5e6));
+            assert_eq!(merged.data[0].part_nanos(2), Some(20e6));
+        }
+
+        #[test]
+        fn keeps_best_times() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: part(5e6),
+                    part_2: part(25e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                }],
+            };
+            let merged = timings.merge(&other, MergePolicy::Best);
+            assert_eq!(merged.data[0].part_nanos(1), Some(5e6));
+            assert_eq!(merged.data[0].part_nanos(2), Some(20e6));
+        }
+
+        #[test]
+        fn keeps_profile_of_contributing_run() {
+            let mut timings = get_mock_timings();
+            timings.data[0].profile = Some("lto".into());
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(1),
+                        part_1: part(50e6),
+                        part_2: None,
+                        profile: Some("native".into()),
+                        variants: vec![],
+                        status: [None; 2],
+                    },
+                    Timing {
+                        day: day!(2),
+                        part_1: part(1e6),
+                        part_2: None,
+                        profile: Some("native".into()),
+                        variants: vec![],
+                        status: [None; 2],
+                    },
+                ],
+            };
+            let merged = timings.merge(&other, MergePolicy::Best);
+            assert_eq!(merged.data[0].profile.as_deref(), Some("lto"));
+            assert_eq!(merged.data[1].profile.as_deref(), Some("native"));
+        }
+
+        #[test]
+        fn keeps_latest_status() {
+            let mut timings = get_mock_timings();
+            timings.data[0].status = [Some(PartStatus::Ok), Some(PartStatus::Ok)];
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: None,
+                    part_2: part(5e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [Some(PartStatus::Panic), None],
+                }],
+            };
+            let merged = timings.merge(&other, MergePolicy::Some);
+            assert_eq!(merged.data[0].part_nanos(1), Some(10e6));
+            assert_eq!(
+                merged.data[0].status,
+                [Some(PartStatus::Panic), Some(PartStatus::Ok)]
+            );
+        }
+
+        #[test]
+        fn lists_changes() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(4),
+                    part_1: part(20e6),
+                    part_2: part(1e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                }],
+            };
+            let merged = timings.merge(&other, MergePolicy::Some);
+            let changes: Vec<String> = timings
+                .changes(&merged)
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            assert_eq!(
+                changes,
+                [
+                    "Day 04 Part 1: 40.0ms -> 20.0ms (-50.0%)",
+                    "Day 04 Part 2: added 1.0ms"
+                ]
+            );
+        }
+
+        fn variant(part: u8, name: &str, nanos: f64) -> VariantTiming {
+            VariantTiming {
+                part,
+                name: name.into(),
+                timing: super::part(nanos).unwrap(),
+            }
+        }
+
+        #[test]
+        fn drops_variants_of_latest_run() {
+            let mut timings = get_mock_timings();
+            timings.data[0].variants = vec![variant(1, "lp", 10e6), variant(1, "bnb", 12e6)];
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: part(8e6),
+                    part_2: part(20e6),
+                    profile: None,
+                    variants: vec![],
+                    status: [None; 2],
+                }],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::Latest);
+            assert!(merged.data[0].variants.is_empty());
+
+            let merged = timings.merge(&other, MergePolicy::Some);
+            assert_eq!(merged.data[0].variants.len(), 2);
+        }
+
+        #[test]
+        fn keeps_variants_matching_best_times() {
+            let mut timings = get_mock_timings();
+            timings.data[0].variants = vec![variant(1, "lp", 10e6), variant(1, "bnb", 12e6)];
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: part(11e6),
+                    part_2: None,
+                    profile: None,
+                    variants: vec![variant(1, "lp", 15e6), variant(1, "bnb", 11e6)],
+                    status: [None; 2],
+                }],
+            };
+
+            let merged = timings.merge(&other, MergePolicy::Best);
+            let day = &merged.data[0];
+            assert_eq!(day.part_nanos(1), Some(10e6));
+
+            let variants: Vec<_> = day
+                .variants(1)
+                .iter()
+                .map(|v| (v.name.as_str(), v.timing.nanos))
+                .collect();
+            assert_eq!(variants, [("lp", 10e6), ("bnb", 11e6)]);
+            assert_eq!(day.part_nanos(1), Some(variants[0].1));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
             let other = get_mock_timings();
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest);
             assert_eq!(merged.data.len(), 3);
         }
 
@@ -377,7 +1367,7 @@ mod tests {
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
             let other = Timings::default();
-            let merged = timings.merge(&other);
+            let merged = timings.merge(&other, MergePolicy::Latest);
             assert_eq!(merged.data.len(), 3);
         }
     }
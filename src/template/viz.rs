@@ -0,0 +1,708 @@
+/// Visualisations of solutions: parts emit frames with the [`viz!`](crate::viz) macro, and `solve N --viz` plays them
+/// in the terminal or exports them as PNG or GIF files. Frames are only recorded in builds with the `viz` feature.
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Write},
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+    thread,
+    time::Duration,
+};
+
+use crate::template::{ANSI_RESET, Day, markdown::terminal_size};
+
+/// How a cell or shape is drawn. Where several meet in one pixel, the later variant wins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Shade {
+    Background,
+    Foreground,
+    Line,
+    Highlight,
+}
+
+/// Colors of the shades, in the order of their variants.
+const PALETTE: [[u8; 3]; 4] = [[15, 15, 35], [204, 204, 204], [0, 153, 0], [255, 255, 102]];
+
+impl Shade {
+    fn rgb(self) -> [u8; 3] {
+        PALETTE[self as usize]
+    }
+}
+
+/// A grid of cells, e.g. a map from the puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Shade>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![Shade::Background; width * height],
+        }
+    }
+
+    /// A grid of the lines of `input`, shading each character with `shade`.
+    pub fn parse(input: &str, shade: impl Fn(char) -> Shade) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let mut grid = Grid::new(width, lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid.set(x, y, shade(c));
+            }
+        }
+        grid
+    }
+
+    /// Shade a cell. Cells outside of the grid are ignored.
+    pub fn set(&mut self, x: usize, y: usize, shade: Shade) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = shade;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Shape {
+    Point((i64, i64), Shade),
+    Line((i64, i64), (i64, i64), Shade),
+}
+
+/// Points and lines in puzzle coordinates, which are scaled to fit the image.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Plot {
+    shapes: Vec<Shape>,
+}
+
+impl Plot {
+    pub fn new() -> Self {
+        Plot::default()
+    }
+
+    #[must_use]
+    pub fn points(mut self, points: impl IntoIterator<Item = (i64, i64)>, shade: Shade) -> Self {
+        self.shapes
+            .extend(points.into_iter().map(|p| Shape::Point(p, shade)));
+        self
+    }
+
+    #[must_use]
+    pub fn line(mut self, from: (i64, i64), to: (i64, i64), shade: Shade) -> Self {
+        self.shapes.push(Shape::Line(from, to, shade));
+        self
+    }
+
+    /// The outline of the closed polygon through `corners`.
+    #[must_use]
+    pub fn polygon(self, corners: &[(i64, i64)], shade: Shade) -> Self {
+        let Some(&last) = corners.last() else {
+            return self;
+        };
+
+        let mut previous = last;
+        corners.iter().fold(self, |plot, &corner| {
+            let plot = plot.line(previous, corner, shade);
+            previous = corner;
+            plot
+        })
+    }
+
+    /// The outline of the rectangle spanned by two opposite corners.
+    #[must_use]
+    pub fn rect(self, a: (i64, i64), b: (i64, i64), shade: Shade) -> Self {
+        self.polygon(&[a, (b.0, a.1), b, (a.0, b.1)], shade)
+    }
+}
+
+/// A frame emitted by a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Frame {
+    Grid(Grid),
+    Plot(Plot),
+}
+
+impl From<Grid> for Frame {
+    fn from(grid: Grid) -> Self {
+        Frame::Grid(grid)
+    }
+}
+
+impl From<Plot> for Frame {
+    fn from(plot: Plot) -> Self {
+        Frame::Plot(plot)
+    }
+}
+
+static FRAMES: Mutex<Vec<Frame>> = Mutex::new(Vec::new());
+
+/// Record a frame. Use the [`viz!`](crate::viz) macro instead, which is compiled out without the `viz` feature.
+pub fn push(frame: Frame) {
+    FRAMES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(frame);
+}
+
+/// Take all frames recorded since the last call.
+pub fn take() -> Vec<Frame> {
+    std::mem::take(&mut *FRAMES.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Emit a visualisation frame, i.e. a [`Grid`](crate::template::viz::Grid) or a [`Plot`](crate::template::viz::Plot).
+///
+/// Frames are only built and recorded with the `viz` feature, which `solve N --viz` enables,
+/// so that benchmark builds do not pay for them:
+/// ```ignore
+/// advent_of_code::viz!(Grid::parse(input, |c| if c == '#' { Shade::Foreground } else { Shade::Background }));
+/// ```
+#[macro_export]
+macro_rules! viz {
+    ($frame:expr) => {{
+        #[cfg(feature = "viz")]
+        $crate::template::viz::push($crate::template::viz::Frame::from($frame));
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A frame rasterized to pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<Shade>,
+}
+
+impl Raster {
+    fn new(width: usize, height: usize) -> Self {
+        Raster {
+            width,
+            height,
+            pixels: vec![Shade::Background; width * height],
+        }
+    }
+
+    fn draw(&mut self, x: usize, y: usize, shade: Shade) {
+        let pixel = &mut self.pixels[y * self.width + x];
+        *pixel = (*pixel).max(shade);
+    }
+
+    fn get(&self, x: usize, y: usize) -> Shade {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x]
+        } else {
+            Shade::Background
+        }
+    }
+
+    /// Rasterize a frame to fit in `max_width` x `max_height` pixels.
+    /// Grids are scaled by whole factors, up when they are small, or down keeping the strongest shade of each block.
+    pub fn render(frame: &Frame, max_width: usize, max_height: usize) -> Self {
+        let (max_width, max_height) = (max_width.max(1), max_height.max(1));
+        match frame {
+            Frame::Grid(grid) => Raster::render_grid(grid, max_width, max_height),
+            Frame::Plot(plot) => Raster::render_plot(plot, max_width, max_height),
+        }
+    }
+
+    fn render_grid(grid: &Grid, max_width: usize, max_height: usize) -> Self {
+        let (width, height) = (grid.width.max(1), grid.height.max(1));
+
+        if width <= max_width && height <= max_height {
+            let scale = (max_width / width).min(max_height / height);
+            let mut raster = Raster::new(grid.width * scale, grid.height * scale);
+            for y in 0..raster.height {
+                for x in 0..raster.width {
+                    raster.draw(x, y, grid.cells[(y / scale) * grid.width + x / scale]);
+                }
+            }
+            raster
+        } else {
+            let factor = width.div_ceil(max_width).max(height.div_ceil(max_height));
+            let mut raster = Raster::new(grid.width.div_ceil(factor), grid.height.div_ceil(factor));
+            for y in 0..grid.height {
+                for x in 0..grid.width {
+                    raster.draw(x / factor, y / factor, grid.cells[y * grid.width + x]);
+                }
+            }
+            raster
+        }
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn render_plot(plot: &Plot, max_width: usize, max_height: usize) -> Self {
+        let coordinates = plot.shapes.iter().flat_map(|shape| match *shape {
+            Shape::Point(p, _) => [p, p],
+            Shape::Line(a, b, _) => [a, b],
+        });
+        let Some((min, max)) = coordinates.fold(None, |bounds, (x, y)| match bounds {
+            None => Some(((x, y), (x, y))),
+            Some((min, max)) => Some(((x.min(min.0), y.min(min.1)), (x.max(max.0), y.max(max.1)))),
+        }) else {
+            return Raster::new(0, 0);
+        };
+
+        let fit = |span: i64, pixels: usize| {
+            if span == 0 {
+                f64::INFINITY
+            } else {
+                (pixels - 1) as f64 / span as f64
+            }
+        };
+        let scale = fit(max.0 - min.0, max_width).min(fit(max.1 - min.1, max_height));
+        let scale = if scale.is_finite() { scale } else { 1.0 };
+
+        let to_pixel = |(x, y): (i64, i64)| {
+            (
+                ((x - min.0) as f64 * scale).round() as i64,
+                ((y - min.1) as f64 * scale).round() as i64,
+            )
+        };
+
+        let (width, height) = to_pixel(max);
+        let mut raster = Raster::new(width as usize + 1, height as usize + 1);
+
+        for shape in &plot.shapes {
+            match *shape {
+                Shape::Point(p, shade) => {
+                    let (x, y) = to_pixel(p);
+                    raster.draw(x as usize, y as usize, shade);
+                }
+                Shape::Line(a, b, shade) => {
+                    for (x, y) in line_pixels(to_pixel(a), to_pixel(b)) {
+                        raster.draw(x as usize, y as usize, shade);
+                    }
+                }
+            }
+        }
+
+        raster
+    }
+
+    /// Render with half blocks, i.e. two rows of pixels per line of text.
+    pub fn to_terminal(&self) -> Vec<String> {
+        let color = |[r, g, b]: [u8; 3]| format!("{r};{g};{b}m");
+
+        (0..self.height)
+            .step_by(2)
+            .map(|y| {
+                let mut line = String::new();
+                let mut previous = None;
+                for x in 0..self.width {
+                    let pixels = (self.get(x, y), self.get(x, y + 1));
+                    if previous != Some(pixels) {
+                        line.push_str(&format!(
+                            "\x1b[38;2;{}\x1b[48;2;{}",
+                            color(pixels.0.rgb()),
+                            color(pixels.1.rgb())
+                        ));
+                        previous = Some(pixels);
+                    }
+                    line.push('▀');
+                }
+                line + ANSI_RESET
+            })
+            .collect()
+    }
+}
+
+/// The pixels of a line, using Bresenham's algorithm.
+fn line_pixels(from: (i64, i64), to: (i64, i64)) -> Vec<(i64, i64)> {
+    let (dx, dy) = ((to.0 - from.0).abs(), -(to.1 - from.1).abs());
+    let (sx, sy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+    let (mut x, mut y) = from;
+    let mut error = dx + dy;
+    let mut pixels = vec![];
+
+    loop {
+        pixels.push((x, y));
+        if (x, y) == to {
+            return pixels;
+        }
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += sy;
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), byte| {
+        let a = (a + u32::from(*byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// A zlib stream of uncompressed blocks, which is all a valid PNG needs.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = u16::try_from(block.len()).unwrap();
+        stream.push(u8::from(blocks.peek().is_none()));
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn palette() -> Vec<u8> {
+    PALETTE.iter().flatten().copied().collect()
+}
+
+/// Encode a raster as an indexed PNG.
+pub fn encode_png(raster: &Raster) -> Vec<u8> {
+    fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        png.extend(u32::try_from(data.len()).unwrap().to_be_bytes());
+        let start = png.len();
+        png.extend(kind);
+        png.extend(data);
+        let crc = crc32(&png[start..]);
+        png.extend(crc.to_be_bytes());
+    }
+
+    let mut header = vec![];
+    header.extend(u32::try_from(raster.width).unwrap().to_be_bytes());
+    header.extend(u32::try_from(raster.height).unwrap().to_be_bytes());
+    // 8 bits per pixel, indexed colors, default compression, filtering and no interlacing.
+    header.extend([8, 3, 0, 0, 0]);
+
+    // every scanline starts with its filter type, which is none.
+    let scanlines: Vec<u8> = raster
+        .pixels
+        .chunks(raster.width.max(1))
+        .flat_map(|row| std::iter::once(0).chain(row.iter().map(|shade| *shade as u8)))
+        .collect();
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"PLTE", &palette());
+    chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// The LZW minimum code size of the GIF image data. Codes are then 8 bits wide, one byte each.
+const GIF_CODE_SIZE: u8 = 7;
+/// Literals between clear codes, so that the code table never grows beyond 8-bit codes.
+const GIF_CLEAR_INTERVAL: usize = 120;
+
+/// Encode rasters as a looping GIF animation, showing each frame for `delay` hundredths of a second.
+pub fn encode_gif(rasters: &[Raster], delay: u16) -> Vec<u8> {
+    let dimension = |f: fn(&Raster) -> usize| {
+        u16::try_from(rasters.iter().map(f).max().unwrap_or(0)).unwrap_or(u16::MAX)
+    };
+    let (width, height) = (dimension(|r| r.width), dimension(|r| r.height));
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    // global color table of 4 colors, background color 0.
+    gif.extend([0xf1, 0, 0]);
+    gif.extend(palette());
+    // loop forever.
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let (clear, end) = (1 << GIF_CODE_SIZE, (1 << GIF_CODE_SIZE) + 1);
+
+    for raster in rasters {
+        gif.extend([0x21, 0xf9, 0x04, 0x04]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0]);
+
+        gif.extend([0x2c, 0, 0, 0, 0]);
+        gif.extend(
+            u16::try_from(raster.width)
+                .unwrap_or(u16::MAX)
+                .to_le_bytes(),
+        );
+        gif.extend(
+            u16::try_from(raster.height)
+                .unwrap_or(u16::MAX)
+                .to_le_bytes(),
+        );
+        gif.push(0);
+
+        let mut codes = vec![];
+        for chunk in raster.pixels.chunks(GIF_CLEAR_INTERVAL) {
+            codes.push(clear);
+            codes.extend(chunk.iter().map(|shade| *shade as u8));
+        }
+        codes.push(end);
+
+        gif.push(GIF_CODE_SIZE);
+        for block in codes.chunks(255) {
+            gif.push(u8::try_from(block.len()).unwrap());
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where `solve N --viz` shows the frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VizOutput {
+    /// Play the frames in the terminal.
+    #[default]
+    Play,
+    /// Export every frame to a PNG file.
+    Png,
+    /// Export all frames to a GIF animation.
+    Gif,
+}
+
+impl FromStr for VizOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "play" => Ok(VizOutput::Play),
+            "png" => Ok(VizOutput::Png),
+            "gif" => Ok(VizOutput::Gif),
+            _ => Err(format!(
+                "Unknown visualisation output \"{s}\", expected play, png or gif."
+            )),
+        }
+    }
+}
+
+impl Display for VizOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            VizOutput::Play => "play",
+            VizOutput::Png => "png",
+            VizOutput::Gif => "gif",
+        };
+        write!(f, "{output}")
+    }
+}
+
+/// Size of exported images.
+const IMAGE_SIZE: usize = 400;
+const FRAME_DELAY: Duration = Duration::from_millis(100);
+/// Longer visualisations skip frames when played in the terminal.
+const MAX_PLAYED_FRAMES: usize = 100;
+
+/// Path of an exported visualisation, e.g. `data/viz/04-2.gif` or `data/viz/10-2-lp-0001.png`.
+#[must_use]
+pub fn get_path_for_viz(
+    day: Day,
+    part: u8,
+    variant: Option<&str>,
+    frame: Option<usize>,
+    extension: &str,
+) -> String {
+    let mut stem = format!("{day}-{part}");
+    if let Some(variant) = variant {
+        stem.push_str(&format!("-{variant}"));
+    }
+    if let Some(frame) = frame {
+        stem.push_str(&format!("-{:04}", frame + 1));
+    }
+    format!("data/viz/{stem}.{extension}")
+}
+
+/// Show the frames recorded while running a part, labeled e.g. "Part 2 (lp)".
+pub fn show(output: VizOutput, day: Day, part: u8, variant: Option<&str>, label: &str) {
+    let frames = take();
+    if frames.is_empty() {
+        return;
+    }
+
+    let write = |path: String, bytes: Vec<u8>| {
+        if let Err(e) = fs::create_dir_all("data/viz").and_then(|()| fs::write(&path, bytes)) {
+            eprintln!("Failed to write \"{path}\": {e}");
+        }
+    };
+
+    match output {
+        VizOutput::Play => play(&frames, label),
+        VizOutput::Png => {
+            for (i, frame) in frames.iter().enumerate() {
+                let raster = Raster::render(frame, IMAGE_SIZE, IMAGE_SIZE);
+                write(
+                    get_path_for_viz(day, part, variant, Some(i), "png"),
+                    encode_png(&raster),
+                );
+            }
+            println!(
+                "{label}: exported {} frames to \"{}\".",
+                frames.len(),
+                get_path_for_viz(day, part, variant, Some(0), "png")
+            );
+        }
+        VizOutput::Gif => {
+            let rasters: Vec<Raster> = frames
+                .iter()
+                .map(|frame| Raster::render(frame, IMAGE_SIZE, IMAGE_SIZE))
+                .collect();
+            #[allow(clippy::cast_possible_truncation)]
+            let delay = (FRAME_DELAY.as_millis() / 10) as u16;
+            let path = get_path_for_viz(day, part, variant, None, "gif");
+            write(path.clone(), encode_gif(&rasters, delay));
+            println!("{label}: exported {} frames to \"{path}\".", frames.len());
+        }
+    }
+}
+
+/// Play frames in the terminal, redrawing each over the previous one. Without a terminal, only the last frame is printed.
+fn play(frames: &[Frame], label: &str) {
+    let (rows, cols) = terminal_size().unwrap_or((24, 80));
+    let step = frames.len().div_ceil(MAX_PLAYED_FRAMES);
+
+    let mut shown: Vec<usize> = (0..frames.len()).step_by(step).collect();
+    if shown.last() != Some(&(frames.len() - 1)) {
+        shown.push(frames.len() - 1);
+    }
+    if !io::stdout().is_terminal() {
+        shown = vec![frames.len() - 1];
+    }
+
+    let mut stdout = io::stdout();
+    let mut previous_height = 0;
+
+    for (n, &i) in shown.iter().enumerate() {
+        let raster = Raster::render(&frames[i], cols, rows.saturating_sub(2) * 2);
+        let lines = raster.to_terminal();
+
+        if previous_height > 0 {
+            print!("\x1b[{previous_height}A\x1b[J");
+        }
+        for line in &lines {
+            println!("{line}");
+        }
+        println!("{label}: frame {}/{}", i + 1, frames.len());
+        let _ = stdout.flush();
+
+        previous_height = lines.len() + 1;
+        if n + 1 < shown.len() {
+            thread::sleep(FRAME_DELAY);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Frame, Grid, Plot, Raster, Shade, adler32, crc32, encode_gif, encode_png, line_pixels,
+    };
+
+    #[test]
+    fn computes_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn draws_lines() {
+        assert_eq!(
+            line_pixels((0, 0), (3, 1)),
+            [(0, 0), (1, 0), (2, 1), (3, 1)]
+        );
+        assert_eq!(line_pixels((2, 2), (2, 0)), [(2, 2), (2, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn scales_grids() {
+        let grid = Grid::parse("#.\n.@", |c| match c {
+            '#' => Shade::Foreground,
+            '@' => Shade::Highlight,
+            _ => Shade::Background,
+        });
+
+        let up = Raster::render(&Frame::Grid(grid.clone()), 4, 5);
+        assert_eq!((up.width, up.height), (4, 4));
+        assert_eq!(up.get(1, 1), Shade::Foreground);
+        assert_eq!(up.get(3, 2), Shade::Highlight);
+
+        // reduced grids keep the strongest shade of each block.
+        let down = Raster::render(&Frame::Grid(grid), 1, 1);
+        assert_eq!(down.pixels, [Shade::Highlight]);
+    }
+
+    #[test]
+    fn fits_plots() {
+        let plot = Plot::new()
+            .rect((0, 0), (1000, 500), Shade::Line)
+            .points([(500, 250)], Shade::Highlight);
+        let raster = Raster::render(&Frame::Plot(plot), 11, 11);
+
+        assert_eq!((raster.width, raster.height), (11, 6));
+        assert_eq!(raster.get(10, 5), Shade::Line);
+        assert_eq!(raster.get(5, 3), Shade::Highlight);
+        assert_eq!(raster.get(5, 2), Shade::Background);
+    }
+
+    #[test]
+    fn encodes_images() {
+        let raster = Raster::render(&Frame::Grid(Grid::new(3, 2)), 3, 2);
+
+        let png = encode_png(&raster);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+        let gif = encode_gif(&[raster.clone(), raster], 10);
+        assert!(gif.starts_with(b"GIF89a\x03\0\x02\0"));
+        assert_eq!(gif.iter().filter(|b| **b == 0x2c).count(), 2);
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+
+    #[test]
+    fn renders_half_blocks() {
+        let mut grid = Grid::new(2, 2);
+        grid.set(0, 1, Shade::Highlight);
+        let lines = Raster::render(&Frame::Grid(grid), 2, 2).to_terminal();
+
+        assert_eq!(
+            lines,
+            [
+                "\x1b[38;2;15;15;35m\x1b[48;2;255;255;102m▀\x1b[38;2;15;15;35m\x1b[48;2;15;15;35m▀\x1b[0m"
+            ]
+        );
+    }
+}